@@ -0,0 +1,486 @@
+//! Headless front-end for the launcher.
+//!
+//! `up` supervises a project in the foreground through the same
+//! `ProcessManager` as the desktop app. While it runs it keeps a runtime
+//! file (supervisor PID + last status) and a plain log file in the config
+//! directory, which is what `down`, `status`, `logs` and `restart` use to
+//! reach it from another shell.
+
+use dev_launcher_core::config::{get_config_dir, load_config, Config, Project};
+use dev_launcher_core::error::AppError;
+use dev_launcher_core::events::{ManagerEvent, StatusEvent};
+use dev_launcher_core::process_manager::{ProcessManager, ServiceSpec};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio::time::{self, Duration};
+
+const USAGE: &str = "\
+Usage: dev-launcher-cli <command> [options]
+
+Commands:
+  up [SERVICE...]       Start services in the foreground and stream their logs
+  down                  Stop the running `up` supervisor for the project
+  restart [SERVICE...]  Stop the running supervisor, then run `up` again
+  status                Show the services of the running supervisor
+  logs [-f]             Print the project log, `-f` keeps following it
+
+Options:
+  -p, --project <ID>    Project to use (defaults to the active project)
+  -h, --help            Show this help";
+
+const LOG_TAIL_LINES: usize = 200;
+const DOWN_TIMEOUT: Duration = Duration::from_secs(15);
+
+enum Command {
+    Up,
+    Down,
+    Restart,
+    Status,
+    Logs { follow: bool },
+}
+
+struct Args {
+    command: Command,
+    project: Option<String>,
+    services: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RuntimeState {
+    pid: u32,
+    project_id: String,
+    status: StatusEvent,
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1).collect()) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("Failed to start runtime: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match runtime.block_on(run(args)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(raw: Vec<String>) -> Result<Option<Args>, String> {
+    let mut iter = raw.into_iter();
+    let command = match iter.next().as_deref() {
+        None | Some("-h") | Some("--help") | Some("help") => return Ok(None),
+        Some("up") => Command::Up,
+        Some("down") => Command::Down,
+        Some("restart") => Command::Restart,
+        Some("status") => Command::Status,
+        Some("logs") => Command::Logs { follow: false },
+        Some(other) => return Err(format!("Unknown command: {}", other)),
+    };
+
+    let mut args = Args {
+        command,
+        project: None,
+        services: Vec::new(),
+    };
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-p" | "--project" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("Missing value for {}", arg))?;
+                args.project = Some(value);
+            }
+            "-f" | "--follow" => match &mut args.command {
+                Command::Logs { follow } => *follow = true,
+                _ => return Err(format!("{} is only valid for `logs`", arg)),
+            },
+            flag if flag.starts_with('-') => return Err(format!("Unknown option: {}", flag)),
+            _ => match args.command {
+                Command::Up | Command::Restart => args.services.push(arg),
+                _ => return Err(format!("Unexpected argument: {}", arg)),
+            },
+        }
+    }
+
+    Ok(Some(args))
+}
+
+async fn run(args: Args) -> Result<(), String> {
+    let config = load_config().ok_or_else(|| AppError::NoConfigLoaded.to_string())?;
+    let project = resolve_project(&config, args.project.as_deref())?;
+
+    match args.command {
+        Command::Up => up(&config, project, &args.services).await,
+        Command::Down => down(&project.id).await,
+        Command::Restart => {
+            down(&project.id).await?;
+            up(&config, project, &args.services).await
+        }
+        Command::Status => {
+            print_status(project);
+            Ok(())
+        }
+        Command::Logs { follow } => logs(&project.id, follow).await,
+    }
+}
+
+fn resolve_project<'a>(
+    config: &'a Config,
+    project_id: Option<&str>,
+) -> Result<&'a Project, String> {
+    let project_id = project_id
+        .map(str::to_string)
+        .or_else(|| config.active_project.clone())
+        .or_else(|| config.projects.first().map(|p| p.id.clone()))
+        .ok_or_else(|| "No project configured".to_string())?;
+
+    config
+        .get_project(&project_id)
+        .ok_or_else(|| AppError::ProjectNotFound { project_id }.to_string())
+}
+
+async fn up(config: &Config, project: &Project, service_ids: &[String]) -> Result<(), String> {
+    if let Some(state) = read_runtime_state(&project.id) {
+        if is_alive(state.pid) {
+            return Err(format!(
+                "Project {} is already running (supervisor PID {})",
+                project.id, state.pid
+            ));
+        }
+    }
+
+    let services = if service_ids.is_empty() {
+        project.services.iter().collect::<Vec<_>>()
+    } else {
+        service_ids
+            .iter()
+            .map(|id| {
+                project
+                    .services
+                    .iter()
+                    .find(|s| &s.id == id)
+                    .ok_or_else(|| {
+                        AppError::ServiceNotFound {
+                            service_id: id.clone(),
+                        }
+                        .to_string()
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    };
+
+    let (event_tx, event_rx) = mpsc::channel::<ManagerEvent>(2048);
+    let manager = Arc::new(ProcessManager::new(event_tx));
+    if let Some(format) = config.timestamp_format.as_deref() {
+        manager
+            .set_timestamp_format(format)
+            .map_err(|e| e.to_string())?;
+    }
+    let printer = tokio::spawn(print_manager_events(project.id.clone(), event_rx));
+
+    write_runtime_state(&RuntimeState {
+        pid: std::process::id(),
+        project_id: project.id.clone(),
+        status: manager.status().await,
+    });
+
+    let mut started = Vec::new();
+    for service in services {
+        match manager
+            .start_service(ServiceSpec::new(project, service))
+            .await
+        {
+            Ok(()) => started.push(service),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+
+    wait_for_shutdown_signal().await;
+
+    for service in started {
+        let _ = manager
+            .stop_service(project.id.clone(), service.id.clone(), service.name.clone())
+            .await;
+    }
+
+    // Reader tasks hold senders until their pipes close; don't hang on a stray one.
+    drop(manager);
+    let _ = time::timeout(Duration::from_secs(2), printer).await;
+    remove_runtime_state(&project.id);
+    Ok(())
+}
+
+async fn down(project_id: &str) -> Result<(), String> {
+    let Some(state) = read_runtime_state(project_id).filter(|s| is_alive(s.pid)) else {
+        remove_runtime_state(project_id);
+        println!("Project {} is not running", project_id);
+        return Ok(());
+    };
+
+    terminate(state.pid);
+
+    let deadline = time::Instant::now() + DOWN_TIMEOUT;
+    while is_alive(state.pid) {
+        if time::Instant::now() >= deadline {
+            return Err(format!(
+                "Supervisor PID {} did not exit within {}s",
+                state.pid,
+                DOWN_TIMEOUT.as_secs()
+            ));
+        }
+        time::sleep(Duration::from_millis(100)).await;
+    }
+
+    println!("Project {} stopped", project_id);
+    Ok(())
+}
+
+fn print_status(project: &Project) {
+    let state = read_runtime_state(&project.id).filter(|s| is_alive(s.pid));
+    match &state {
+        Some(state) => println!(
+            "{} ({}): supervisor PID {}",
+            project.name, project.id, state.pid
+        ),
+        None => println!("{} ({}): not running", project.name, project.id),
+    }
+
+    for service in &project.services {
+        let composite_id = format!("{}:{}", project.id, service.id);
+        let status = state
+            .as_ref()
+            .and_then(|s| s.status.services.get(&composite_id));
+        let running = status.map(|s| s.running).unwrap_or(false);
+        let url = status.and_then(|s| s.url.as_deref()).unwrap_or("");
+        println!(
+            "  {:<20} {:<8} {}",
+            service.id,
+            if running { "running" } else { "stopped" },
+            url
+        );
+    }
+}
+
+async fn logs(project_id: &str, follow: bool) -> Result<(), String> {
+    let path = log_path(project_id).ok_or("Could not determine config directory")?;
+    let mut file = match File::open(&path) {
+        Ok(file) => file,
+        Err(_) if follow => {
+            // Wait for the supervisor to create the file.
+            loop {
+                time::sleep(Duration::from_millis(200)).await;
+                if let Ok(file) = File::open(&path) {
+                    break file;
+                }
+            }
+        }
+        Err(_) => return Ok(()),
+    };
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .map_err(|e| e.to_string())?;
+    let lines: Vec<&str> = content.lines().collect();
+    for line in &lines[lines.len().saturating_sub(LOG_TAIL_LINES)..] {
+        println!("{}", line);
+    }
+
+    if !follow {
+        return Ok(());
+    }
+
+    let mut position = file.stream_position().map_err(|e| e.to_string())?;
+    let mut buffer = String::new();
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+            _ = time::sleep(Duration::from_millis(200)) => {}
+        }
+
+        let len = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        if len < position {
+            // Truncated by a new `up` run: start over from the top.
+            position = 0;
+        }
+        if len == position {
+            continue;
+        }
+
+        file = File::open(&path).map_err(|e| e.to_string())?;
+        file.seek(SeekFrom::Start(position))
+            .map_err(|e| e.to_string())?;
+        buffer.clear();
+        file.read_to_string(&mut buffer)
+            .map_err(|e| e.to_string())?;
+        position += buffer.len() as u64;
+        print!("{}", buffer);
+        let _ = std::io::stdout().flush();
+    }
+}
+
+async fn print_manager_events(project_id: String, mut event_rx: mpsc::Receiver<ManagerEvent>) {
+    let mut log_file = log_path(&project_id).and_then(|path| {
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(path)
+            .ok()
+    });
+    let mut services = HashMap::new();
+
+    while let Some(event) = event_rx.recv().await {
+        match event {
+            ManagerEvent::Log(log) => {
+                let line = format!("{} {}", log.timestamp, log.terminal_text());
+                println!("{}", line);
+                if let Some(file) = log_file.as_mut() {
+                    let _ = writeln!(file, "{}", line);
+                }
+            }
+            ManagerEvent::Status(status) => {
+                services = status.services.clone();
+                write_runtime_state(&RuntimeState {
+                    pid: std::process::id(),
+                    project_id: project_id.clone(),
+                    status,
+                });
+            }
+            ManagerEvent::ServiceUrl {
+                service_id,
+                url,
+                seq,
+                time,
+            } => {
+                if let Some(service) = services.get_mut(&service_id) {
+                    service.url = Some(url);
+                }
+                write_runtime_state(&RuntimeState {
+                    pid: std::process::id(),
+                    project_id: project_id.clone(),
+                    status: StatusEvent {
+                        services: services.clone(),
+                        seq,
+                        time,
+                    },
+                });
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+async fn wait_for_shutdown_signal() {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    match signal(SignalKind::terminate()) {
+        Ok(mut sigterm) => {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {}
+                _ = sigterm.recv() => {}
+            }
+        }
+        Err(_) => {
+            let _ = tokio::signal::ctrl_c().await;
+        }
+    }
+}
+
+#[cfg(not(unix))]
+async fn wait_for_shutdown_signal() {
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+#[cfg(unix)]
+fn is_alive(pid: u32) -> bool {
+    unsafe { libc::kill(pid as i32, 0) == 0 }
+}
+
+#[cfg(not(unix))]
+fn is_alive(pid: u32) -> bool {
+    std::process::Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {}", pid), "/NH"])
+        .output()
+        .map(|out| String::from_utf8_lossy(&out.stdout).contains(&pid.to_string()))
+        .unwrap_or(false)
+}
+
+#[cfg(unix)]
+fn terminate(pid: u32) {
+    unsafe {
+        libc::kill(pid as i32, libc::SIGTERM);
+    }
+}
+
+#[cfg(not(unix))]
+fn terminate(pid: u32) {
+    let _ = std::process::Command::new("taskkill")
+        .args(["/T", "/PID", &pid.to_string()])
+        .output();
+}
+
+fn runtime_dir() -> Option<PathBuf> {
+    get_config_dir().map(|dir| dir.join("run"))
+}
+
+fn runtime_state_path(project_id: &str) -> Option<PathBuf> {
+    runtime_dir().map(|dir| dir.join(format!("{}.json", project_id)))
+}
+
+fn log_path(project_id: &str) -> Option<PathBuf> {
+    runtime_dir().map(|dir| dir.join(format!("{}.log", project_id)))
+}
+
+fn read_runtime_state(project_id: &str) -> Option<RuntimeState> {
+    let content = fs::read_to_string(runtime_state_path(project_id)?).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_runtime_state(state: &RuntimeState) {
+    let Some(path) = runtime_state_path(&state.project_id) else {
+        return;
+    };
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if let Ok(content) = serde_json::to_string_pretty(state) {
+        let tmp_path = path.with_extension("json.tmp");
+        if fs::write(&tmp_path, content).is_ok() {
+            let _ = fs::rename(&tmp_path, &path);
+        }
+    }
+}
+
+fn remove_runtime_state(project_id: &str) {
+    if let Some(path) = runtime_state_path(project_id) {
+        let _ = fs::remove_file(path);
+    }
+}
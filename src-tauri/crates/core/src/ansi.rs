@@ -0,0 +1,220 @@
+//! ANSI escape handling for captured output.
+//!
+//! Services colour their own output; [`parse`] turns those escapes into plain
+//! text plus [`StyleSpan`]s so consumers never have to strip them, and
+//! [`render`] turns them back into escapes for terminal output.
+
+use serde::{Deserialize, Serialize};
+
+/// A colour from an SGR sequence.
+///
+/// Serializes as a palette index (`196`) or an `[r, g, b]` triple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Color {
+    /// 256-colour palette index; 0-15 are the themeable basic colours.
+    Indexed(u8),
+    Rgb([u8; 3]),
+}
+
+/// Text attributes in effect over a span.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Style {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fg: Option<Color>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bg: Option<Color>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub bold: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub dim: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub italic: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub underline: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+impl Style {
+    fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Applies the SGR parameters of one `ESC [ ... m` sequence.
+    fn apply(&mut self, params: &str) {
+        let mut codes = params
+            .split([';', ':'])
+            .map(|code| code.parse::<u16>().unwrap_or(0));
+        // `ESC [ m` is a reset.
+        let mut next = if params.is_empty() {
+            Some(0)
+        } else {
+            codes.next()
+        };
+        while let Some(code) = next {
+            match code {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                22 => {
+                    self.bold = false;
+                    self.dim = false;
+                }
+                23 => self.italic = false,
+                24 => self.underline = false,
+                30..=37 => self.fg = Some(Color::Indexed((code - 30) as u8)),
+                38 => self.fg = extended_color(&mut codes),
+                39 => self.fg = None,
+                40..=47 => self.bg = Some(Color::Indexed((code - 40) as u8)),
+                48 => self.bg = extended_color(&mut codes),
+                49 => self.bg = None,
+                90..=97 => self.fg = Some(Color::Indexed((code - 90 + 8) as u8)),
+                100..=107 => self.bg = Some(Color::Indexed((code - 100 + 8) as u8)),
+                _ => {}
+            }
+            next = codes.next();
+        }
+    }
+
+    /// The SGR parameters selecting this style from a reset.
+    fn sgr_params(&self) -> String {
+        let mut params = Vec::new();
+        if self.bold {
+            params.push("1".to_string());
+        }
+        if self.dim {
+            params.push("2".to_string());
+        }
+        if self.italic {
+            params.push("3".to_string());
+        }
+        if self.underline {
+            params.push("4".to_string());
+        }
+        if let Some(fg) = self.fg {
+            params.push(color_params(38, fg));
+        }
+        if let Some(bg) = self.bg {
+            params.push(color_params(48, bg));
+        }
+        params.join(";")
+    }
+}
+
+/// Reads the `5;n` or `2;r;g;b` tail of a `38`/`48` parameter.
+fn extended_color(codes: &mut impl Iterator<Item = u16>) -> Option<Color> {
+    match codes.next()? {
+        5 => codes.next().map(|n| Color::Indexed(n.min(255) as u8)),
+        2 => {
+            let mut channel = || codes.next().map(|c| c.min(255) as u8);
+            Some(Color::Rgb([channel()?, channel()?, channel()?]))
+        }
+        _ => None,
+    }
+}
+
+fn color_params(base: u8, color: Color) -> String {
+    match color {
+        Color::Indexed(n) => format!("{};5;{}", base, n),
+        Color::Rgb([r, g, b]) => format!("{};2;{};{};{}", base, r, g, b),
+    }
+}
+
+/// A styled range of a log message, in characters (Unicode scalar values).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StyleSpan {
+    pub start: usize,
+    pub end: usize,
+    #[serde(flatten)]
+    pub style: Style,
+}
+
+/// Splits `input` into its visible text and the styled spans within it.
+///
+/// SGR sequences become spans; other escapes (cursor moves, line clears,
+/// OSC titles and hyperlinks) are dropped. Unstyled text gets no span.
+pub fn parse(input: &str) -> (String, Vec<StyleSpan>) {
+    let mut text = String::with_capacity(input.len());
+    let mut spans: Vec<StyleSpan> = Vec::new();
+    let mut style = Style::default();
+    let mut len = 0;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            text.push(c);
+            len += 1;
+            if style.is_plain() {
+                continue;
+            }
+            match spans.last_mut() {
+                Some(span) if span.end == len - 1 && span.style == style => span.end = len,
+                _ => spans.push(StyleSpan {
+                    start: len - 1,
+                    end: len,
+                    style,
+                }),
+            }
+            continue;
+        }
+
+        match chars.next() {
+            Some('[') => {
+                let mut params = String::new();
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        if c == 'm' {
+                            style.apply(&params);
+                        }
+                        break;
+                    }
+                    params.push(c);
+                }
+            }
+            // OSC, terminated by BEL or `ESC \`.
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    (text, spans)
+}
+
+/// Re-applies `spans` to `text` as SGR escapes, for terminal output.
+pub fn render(text: &str, spans: &[StyleSpan]) -> String {
+    if spans.is_empty() {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len() + spans.len() * 12);
+    let mut spans = spans.iter().peekable();
+    for (index, c) in text.chars().enumerate() {
+        if let Some(span) = spans.peek() {
+            if span.start == index {
+                out.push_str(&format!("\x1b[{}m", span.style.sgr_params()));
+            }
+        }
+        out.push(c);
+        if let Some(span) = spans.peek() {
+            if span.end == index + 1 {
+                out.push_str("\x1b[0m");
+                spans.next();
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,149 @@
+//! Launcher configuration, persisted as JSON in `~/.dev-stack-launcher`.
+
+use crate::error::AppError;
+use crate::log_format::LogFormat;
+use crate::log_level::LevelRule;
+use crate::multiline::MultilineConfig;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// A long-running command started in `path`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Service {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+    pub command: String,
+    /// Watch the output for a dev-server URL (e.g. Vite's `Local:` line).
+    #[serde(default)]
+    pub detect_url: bool,
+    /// How to parse output lines into message, level and fields.
+    #[serde(default)]
+    pub log_format: LogFormat,
+    /// Custom regex → level mappings, tried before the built-in patterns.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub level_rules: Vec<LevelRule>,
+    /// Group stack traces into one entry; off when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multiline: Option<MultilineConfig>,
+    /// Env files (relative to `path`) whose values are masked in the logs,
+    /// besides `.env` and `.env.local`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env_files: Vec<String>,
+}
+
+/// A named group of services, shown as one tab in the app.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    pub id: String,
+    pub name: String,
+    pub services: Vec<Service>,
+    /// Extra secret regexes masked in this project's logs, on top of the
+    /// built-in detectors (see `redact`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redact_patterns: Vec<String>,
+}
+
+/// Settings for the optional SSE log server (see `log_server`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogServerConfig {
+    #[serde(default = "default_log_server_host")]
+    pub host: String,
+    pub port: u16,
+}
+
+fn default_log_server_host() -> String {
+    "127.0.0.1".to_string()
+}
+
+/// Root of `config.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Config {
+    pub version: u32,
+    pub active_project: Option<String>,
+    pub projects: Vec<Project>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_server: Option<LogServerConfig>,
+    /// chrono `strftime` format of displayed log times, e.g. `%Y-%m-%d %H:%M:%S`;
+    /// `%H:%M:%S%.3f` when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp_format: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            active_project: None,
+            projects: Vec::new(),
+            log_server: None,
+            timestamp_format: None,
+        }
+    }
+}
+
+/// `~/.dev-stack-launcher`, which also holds runtime files such as sockets.
+pub fn get_config_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".dev-stack-launcher"))
+}
+
+/// Path of `config.json` inside [`get_config_dir`].
+pub fn get_config_path() -> Option<PathBuf> {
+    get_config_dir().map(|dir| dir.join("config.json"))
+}
+
+/// Reads the config, returning `None` if it is missing or unreadable.
+pub fn load_config() -> Option<Config> {
+    let path = get_config_path()?;
+    if !path.exists() {
+        return None;
+    }
+    let content = fs::read_to_string(&path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Writes the config atomically (temp file + rename).
+pub fn save_config(config: &Config) -> Result<(), AppError> {
+    let dir = get_config_dir().ok_or_else(|| AppError::SaveConfig {
+        message: "Could not determine config directory".to_string(),
+    })?;
+    let path = get_config_path().ok_or_else(|| AppError::SaveConfig {
+        message: "Could not determine config path".to_string(),
+    })?;
+
+    // Create directory if it doesn't exist
+    if !dir.exists() {
+        fs::create_dir_all(&dir).map_err(|e| AppError::SaveConfig {
+            message: e.to_string(),
+        })?;
+    }
+
+    let content = serde_json::to_string_pretty(config).map_err(|e| AppError::SaveConfig {
+        message: e.to_string(),
+    })?;
+
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, content).map_err(|e| AppError::SaveConfig {
+        message: e.to_string(),
+    })?;
+
+    fs::rename(&tmp_path, &path).map_err(|e| AppError::SaveConfig {
+        message: e.to_string(),
+    })?;
+
+    Ok(())
+}
+
+impl Config {
+    /// Looks up a project by ID.
+    pub fn get_project(&self, project_id: &str) -> Option<&Project> {
+        self.projects.iter().find(|p| p.id == project_id)
+    }
+
+    /// Looks up a service by project and service ID.
+    pub fn get_service(&self, project_id: &str, service_id: &str) -> Option<&Service> {
+        self.get_project(project_id)
+            .and_then(|p| p.services.iter().find(|s| s.id == service_id))
+    }
+}
@@ -1,6 +1,10 @@
 use serde::Serialize;
 use thiserror::Error;
 
+/// Errors returned by the core and the commands built on it.
+///
+/// Serializes as `{ "code": "snake_case_variant", ...fields }` so clients
+/// can branch on `code`.
 #[derive(Debug, Error, Serialize)]
 #[serde(tag = "code", rename_all = "snake_case")]
 pub enum AppError {
@@ -15,7 +19,18 @@ pub enum AppError {
     #[error("Empty command")]
     EmptyCommand,
     #[error("Failed to start {service_name}: {message}")]
-    ProcessStartFailed { service_name: String, message: String },
+    ProcessStartFailed {
+        service_name: String,
+        message: String,
+    },
+    #[error("Invalid level rule `{pattern}`: {message}")]
+    InvalidLevelRule { pattern: String, message: String },
+    #[error("Invalid multiline pattern `{pattern}`: {message}")]
+    InvalidMultilinePattern { pattern: String, message: String },
+    #[error("Invalid redact pattern `{pattern}`: {message}")]
+    InvalidRedactPattern { pattern: String, message: String },
+    #[error("Invalid timestamp format `{format}`")]
+    InvalidTimestampFormat { format: String },
     #[error("Failed to save config: {message}")]
     SaveConfig { message: String },
     #[error("Failed to open browser: {message}")]
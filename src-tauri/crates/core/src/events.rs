@@ -0,0 +1,216 @@
+//! Events emitted by the `ProcessManager` while services run.
+
+use crate::ansi::{self, StyleSpan};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Display format of [`LogEvent::timestamp`] unless the config sets
+/// `timestamp_format`.
+pub const DEFAULT_TIMESTAMP_FORMAT: &str = "%H:%M:%S%.3f";
+
+/// Severity of a log line, as reported by the service itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
+impl Severity {
+    /// Parses the usual level names (`INFO`, `warning`, `err`, `crit`...).
+    pub fn parse(level: &str) -> Option<Self> {
+        match level.trim().to_ascii_lowercase().as_str() {
+            "trace" | "verbose" => Some(Self::Trace),
+            "debug" | "dbug" => Some(Self::Debug),
+            "info" | "information" | "notice" => Some(Self::Info),
+            "warn" | "warning" => Some(Self::Warn),
+            "error" | "err" => Some(Self::Error),
+            "fatal" | "critical" | "crit" | "panic" | "emerg" | "alert" => Some(Self::Fatal),
+            _ => None,
+        }
+    }
+
+    /// Maps pino/bunyan numeric levels (10 = trace ... 60 = fatal).
+    pub fn from_pino(level: u64) -> Option<Self> {
+        match level {
+            0..=14 => Some(Self::Trace),
+            15..=24 => Some(Self::Debug),
+            25..=34 => Some(Self::Info),
+            35..=44 => Some(Self::Warn),
+            45..=54 => Some(Self::Error),
+            55.. => Some(Self::Fatal),
+        }
+    }
+
+    /// The coarse `LogEvent::level` this severity is filed under.
+    pub fn display_level(self) -> &'static str {
+        match self {
+            Self::Error | Self::Fatal => "error",
+            Self::Warn => "warn",
+            _ => "normal",
+        }
+    }
+}
+
+/// Where a log line came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stream {
+    Stdout,
+    Stderr,
+    /// Written by the manager itself.
+    System,
+}
+
+/// One captured output line, or a `system` message from the manager.
+///
+/// Carries content only: clients render the `[SERVICE]` label themselves from
+/// `source` and `level`, or call [`LogEvent::terminal_text`].
+#[derive(Clone, Serialize)]
+pub struct LogEvent {
+    /// Lowercased service name, or `system`.
+    pub source: String,
+    /// ID of the service that printed the line; `None` for `system` messages.
+    pub service_id: Option<String>,
+    /// `normal`, `warn` or `error`.
+    pub level: String,
+    pub stream: Stream,
+    /// The text to display, without ANSI escapes: the line itself, or the
+    /// rendered message and fields of a structured line.
+    pub message: String,
+    /// Colours and attributes the service applied to `message`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub spans: Vec<StyleSpan>,
+    /// The line exactly as the service printed it, escapes included. Grouped
+    /// entries (see `multiline`) join their lines with `\n`.
+    pub raw: String,
+    /// Number of output lines in this entry.
+    pub line_count: usize,
+    /// Level read from the line (see `log_format` and `log_level`), when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    /// Structured fields of a `json`/`logfmt` line, minus message and level.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Map<String, Value>>,
+    /// Position among every event of the manager (see [`ManagerEvent::seq`]).
+    pub seq: u64,
+    /// When the event was emitted, in UTC (RFC 3339).
+    pub time: DateTime<Utc>,
+    /// When the (first) line was read from the service, in UTC (RFC 3339).
+    pub captured_at: DateTime<Utc>,
+    /// `captured_at` in local time, in the configured display format.
+    pub timestamp: String,
+    pub project_id: String,
+}
+
+impl LogEvent {
+    /// The ANSI-coloured `[SERVICE]` / `[SERVICE ERROR]` label.
+    pub fn terminal_prefix(&self) -> String {
+        let color_code = match self.level.as_str() {
+            "error" => "\x1b[38;5;196m",
+            "warn" => "\x1b[38;5;220m",
+            _ if self.stream == Stream::System => "\x1b[38;5;214m",
+            _ => "\x1b[38;5;75m",
+        };
+        let source = self.source.to_uppercase();
+        let label = match self.level.as_str() {
+            "error" => format!("[{} ERROR]", source),
+            "warn" => format!("[{} WARN]", source),
+            _ => format!("[{}]", source),
+        };
+        format!("{}{}\x1b[0m ", color_code, label)
+    }
+
+    /// The prefixed, coloured line, for terminal consumers.
+    pub fn terminal_text(&self) -> String {
+        format!(
+            "{}{}",
+            self.terminal_prefix(),
+            ansi::render(&self.message, &self.spans)
+        )
+    }
+}
+
+/// Runtime state of one service.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ServiceStatus {
+    pub running: bool,
+    pub url: Option<String>,
+}
+
+/// Snapshot of every known service.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct StatusEvent {
+    /// Keyed by `project_id:service_id`.
+    pub services: HashMap<String, ServiceStatus>,
+    /// `0` for snapshots read with `ProcessManager::status` rather than emitted.
+    #[serde(default)]
+    pub seq: u64,
+    #[serde(default)]
+    pub time: DateTime<Utc>,
+}
+
+/// Everything a `ProcessManager` reports to its [`EventSink`](crate::sink::EventSink).
+///
+/// Serializes as `{ "type": "log" | "status" | "service_url", "payload": ... }`.
+/// Every payload carries the event's `seq` and emission `time`.
+#[derive(Clone, Serialize)]
+#[serde(tag = "type", content = "payload", rename_all = "snake_case")]
+pub enum ManagerEvent {
+    Log(LogEvent),
+    Status(StatusEvent),
+    /// A dev-server URL was detected; `service_id` is `project_id:service_id`.
+    ServiceUrl {
+        service_id: String,
+        url: String,
+        seq: u64,
+        time: DateTime<Utc>,
+    },
+}
+
+impl ManagerEvent {
+    /// Sequence number, increasing by one with each event a manager emits
+    /// (from 1), across all services and streams. Orders and deduplicates
+    /// events regardless of the order they arrive in.
+    pub fn seq(&self) -> u64 {
+        match self {
+            Self::Log(log) => log.seq,
+            Self::Status(status) => status.seq,
+            Self::ServiceUrl { seq, .. } => *seq,
+        }
+    }
+
+    /// Sets the sequence number and emission time, and renders the display
+    /// timestamp of log events.
+    pub(crate) fn stamp(&mut self, seq: u64, time: DateTime<Utc>, timestamp_format: &str) {
+        match self {
+            Self::Log(log) => {
+                log.seq = seq;
+                log.time = time;
+                log.timestamp = log
+                    .captured_at
+                    .with_timezone(&chrono::Local)
+                    .format(timestamp_format)
+                    .to_string();
+            }
+            Self::Status(status) => {
+                status.seq = seq;
+                status.time = time;
+            }
+            Self::ServiceUrl {
+                seq: event_seq,
+                time: event_time,
+                ..
+            } => {
+                *event_seq = seq;
+                *event_time = time;
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Process supervision core of Dev Stack Launcher.
+//!
+//! This crate has no Tauri dependency: the desktop app, the headless CLI and
+//! any other embedder drive services through the same [`ProcessManager`].
+//!
+//! - [`config`] loads and saves the launcher configuration.
+//! - [`process_manager`] starts and stops services in their own process
+//!   groups and captures their output.
+//! - [`events`] defines the [`ManagerEvent`] stream those services produce,
+//!   delivered to any [`EventSink`].
+//! - [`ansi`] splits the colours services print from their text.
+//! - [`log_server`] optionally re-publishes that stream over HTTP.
+//!
+//! ```no_run
+//! use dev_launcher_core::config::load_config;
+//! use dev_launcher_core::events::ManagerEvent;
+//! use dev_launcher_core::process_manager::{ProcessManager, ServiceSpec};
+//! use tokio::sync::mpsc;
+//!
+//! # async fn run() -> Result<(), dev_launcher_core::error::AppError> {
+//! let (event_tx, mut event_rx) = mpsc::channel::<ManagerEvent>(1024);
+//! let manager = ProcessManager::new(event_tx);
+//!
+//! let config = load_config().unwrap_or_default();
+//! let project = &config.projects[0];
+//! manager
+//!     .start_service(ServiceSpec::new(project, &project.services[0]))
+//!     .await?;
+//!
+//! while let Some(event) = event_rx.recv().await {
+//!     if let ManagerEvent::Log(log) = event {
+//!         println!("{}", log.terminal_text());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod ansi;
+pub mod config;
+pub mod error;
+pub mod events;
+pub mod log_format;
+pub mod log_level;
+pub mod log_server;
+pub mod multiline;
+mod process;
+pub mod process_manager;
+pub mod redact;
+pub mod sink;
+
+pub use error::AppError;
+pub use events::ManagerEvent;
+pub use process_manager::{ProcessManager, ServiceSpec};
+pub use sink::EventSink;
@@ -0,0 +1,181 @@
+//! Parsing of structured (`json`, `logfmt`) service output.
+
+use crate::events::Severity;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// How a service formats its output lines.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Free text; the level comes from the stream.
+    #[default]
+    Plain,
+    /// One JSON object per line (pino, bunyan, `tracing-subscriber` json...).
+    Json,
+    /// `key=value` pairs, as written by logrus, slog, Go's `log/slog`...
+    Logfmt,
+}
+
+/// A line decomposed by [`parse_line`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedLine {
+    pub message: String,
+    pub severity: Option<Severity>,
+    /// Every other key of the line.
+    pub fields: Map<String, Value>,
+}
+
+const MESSAGE_KEYS: &[&str] = &["msg", "message"];
+const LEVEL_KEYS: &[&str] = &["level", "lvl", "severity"];
+
+/// Keys kept in `fields` but left out of the rendered text: pino/bunyan and
+/// tracing boilerplate that would drown the message.
+const QUIET_KEYS: &[&str] = &[
+    "time",
+    "timestamp",
+    "ts",
+    "pid",
+    "hostname",
+    "v",
+    "name",
+    "target",
+];
+
+/// Parses `line` according to `format`.
+///
+/// Returns `None` for [`LogFormat::Plain`] and for lines that are not in the
+/// expected format (start-up banners, panics...), which are then shown as is.
+pub fn parse_line(format: LogFormat, line: &str) -> Option<ParsedLine> {
+    let fields = match format {
+        LogFormat::Plain => return None,
+        LogFormat::Json => parse_json(line)?,
+        LogFormat::Logfmt => parse_logfmt(line)?,
+    };
+    Some(decompose(fields))
+}
+
+impl ParsedLine {
+    /// The message followed by its notable fields, e.g.
+    /// `request completed status=200 path="/api"`.
+    pub fn render(&self) -> String {
+        let mut text = self.message.clone();
+        for (key, value) in &self.fields {
+            if QUIET_KEYS.contains(&key.as_str()) {
+                continue;
+            }
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            text.push_str(key);
+            text.push('=');
+            match value {
+                Value::String(s) if s.contains(char::is_whitespace) || s.is_empty() => {
+                    text.push_str(&format!("{:?}", s));
+                }
+                Value::String(s) => text.push_str(s),
+                other => text.push_str(&other.to_string()),
+            }
+        }
+        text
+    }
+}
+
+fn parse_json(line: &str) -> Option<Map<String, Value>> {
+    let trimmed = line.trim();
+    if !trimmed.starts_with('{') {
+        return None;
+    }
+    match serde_json::from_str::<Value>(trimmed).ok()? {
+        Value::Object(mut fields) => {
+            // tracing-subscriber nests the message and span fields under `fields`.
+            if let Some(Value::Object(nested)) = fields.remove("fields") {
+                for (key, value) in nested {
+                    fields.entry(key).or_insert(value);
+                }
+            }
+            Some(fields)
+        }
+        _ => None,
+    }
+}
+
+fn parse_logfmt(line: &str) -> Option<Map<String, Value>> {
+    let mut fields = Map::new();
+    let mut chars = line.trim().chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut key = String::new();
+        while let Some(c) = chars.next_if(|c| *c != '=' && !c.is_whitespace()) {
+            key.push(c);
+        }
+
+        let value = if chars.next_if_eq(&'=').is_some() {
+            let mut value = String::new();
+            if chars.next_if_eq(&'"').is_some() {
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => {
+                            if let Some(escaped) = chars.next() {
+                                value.push(match escaped {
+                                    'n' => '\n',
+                                    't' => '\t',
+                                    other => other,
+                                });
+                            }
+                        }
+                        other => value.push(other),
+                    }
+                }
+            } else {
+                while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+                    value.push(c);
+                }
+            }
+            Value::String(value)
+        } else {
+            // A bare word. Lines made of bare words are plain text.
+            Value::Bool(true)
+        };
+
+        if !key.is_empty() {
+            fields.insert(key, value);
+        }
+    }
+
+    let has_pairs = fields.values().any(|value| value.is_string());
+    has_pairs.then_some(fields)
+}
+
+fn decompose(mut fields: Map<String, Value>) -> ParsedLine {
+    let message = MESSAGE_KEYS
+        .iter()
+        .find_map(|key| match fields.remove(*key) {
+            Some(Value::String(message)) => Some(message),
+            Some(other) => Some(other.to_string()),
+            None => None,
+        })
+        .unwrap_or_default();
+
+    let severity = LEVEL_KEYS.iter().find_map(|key| {
+        let severity = match fields.get(*key)? {
+            Value::String(level) => Severity::parse(level),
+            Value::Number(level) => level.as_u64().and_then(Severity::from_pino),
+            _ => None,
+        }?;
+        fields.remove(*key);
+        Some(severity)
+    });
+
+    ParsedLine {
+        message,
+        severity,
+        fields,
+    }
+}
@@ -0,0 +1,80 @@
+//! Level classification of plain-text output lines.
+//!
+//! Many tools write their normal progress to stderr, so the stream alone says
+//! little about a line. Each service gets a [`LevelClassifier`] built from its
+//! custom [`LevelRule`]s followed by built-in patterns for the usual
+//! `ERROR`/`WARN`/`INFO` prefixes.
+
+use crate::error::AppError;
+use crate::events::Severity;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// Files lines matching `pattern` under `level`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LevelRule {
+    pub pattern: String,
+    pub level: Severity,
+}
+
+/// Compiled level rules of one service.
+#[derive(Debug, Clone, Default)]
+pub struct LevelClassifier {
+    rules: Vec<(Regex, Severity)>,
+}
+
+impl LevelClassifier {
+    /// Compiles `rules`, failing on the first invalid pattern.
+    pub fn new(rules: &[LevelRule]) -> Result<Self, AppError> {
+        let rules = rules
+            .iter()
+            .map(|rule| {
+                Regex::new(&rule.pattern)
+                    .map(|regex| (regex, rule.level))
+                    .map_err(|e| AppError::InvalidLevelRule {
+                        pattern: rule.pattern.clone(),
+                        message: e.to_string(),
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { rules })
+    }
+
+    /// The level of `line`: the first matching custom rule, else a built-in
+    /// pattern, else `None` (the caller falls back to the stream).
+    pub fn classify(&self, line: &str) -> Option<Severity> {
+        self.rules
+            .iter()
+            .find(|(regex, _)| regex.is_match(line))
+            .map(|(_, level)| *level)
+            .or_else(|| builtin_level(line))
+    }
+}
+
+/// Matches a level keyword at the start of the line, possibly after a
+/// timestamp or bracketed tags: `ERROR: ...`, `[WARN] ...`,
+/// `2024-01-01 12:00:00 INFO ...`, `WARNING:root:...` (Python logging),
+/// `ERROR in ./src` (webpack).
+fn builtin_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(
+            r"^\s*(?:[\d\[(][^\s]*\s+){0,3}?[\[(]?(TRACE|DEBUG|INFO|NOTICE|WARN|WARNING|ERROR|ERR|FATAL|CRITICAL|PANIC)[\])]?(?:[\s:\]|-]|$)",
+        )
+        .unwrap()
+    })
+}
+
+/// rustc/cargo diagnostics: `error[E0308]: ...`, `warning: unused import`.
+fn compiler_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"^\s*(error|warning)(?:\[\w+\])?:").unwrap())
+}
+
+fn builtin_level(line: &str) -> Option<Severity> {
+    builtin_regex()
+        .captures(line)
+        .or_else(|| compiler_regex().captures(line))
+        .and_then(|captures| Severity::parse(&captures[1]))
+}
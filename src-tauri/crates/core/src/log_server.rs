@@ -0,0 +1,307 @@
+//! Optional HTTP endpoint streaming `ManagerEvent`s as Server-Sent Events.
+//!
+//! `GET /events` emits `log`, `status` and `service_url` events; the
+//! `project`, `service` and `level` query parameters narrow the stream, and
+//! `terminal=1` adds the ANSI-coloured, prefixed `text` of each log line.
+//! `GET /` serves a minimal page tailing the same stream in a browser.
+
+use crate::config::LogServerConfig;
+use crate::events::{LogEvent, ManagerEvent, StatusEvent};
+use crate::process_manager::ProcessManager;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tokio::time::{self, Duration};
+
+const MAX_REQUEST_HEAD: usize = 8192;
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+const INDEX_HTML: &str = r#"<!doctype html>
+<html>
+<head><meta charset="utf-8"><title>Dev Stack Launcher logs</title>
+<style>body{margin:0;background:#1e1e1e;color:#d4d4d4;font:13px monospace}pre{margin:0;padding:8px;white-space:pre-wrap}.error{color:#f14c4c}.warn{color:#cca700}</style>
+</head>
+<body><pre id="out"></pre>
+<script>
+const out = document.getElementById('out');
+const source = new EventSource('/events' + location.search);
+source.addEventListener('log', (e) => {
+  const log = JSON.parse(e.data);
+  const line = document.createElement('div');
+  const label = log.source.toUpperCase() + (log.level === 'normal' ? '' : ' ' + log.level.toUpperCase());
+  line.textContent = log.timestamp + ' [' + label + '] ' + log.message;
+  if (log.level !== 'normal') line.className = log.level;
+  out.appendChild(line);
+  window.scrollTo(0, document.body.scrollHeight);
+});
+</script>
+</body>
+</html>
+"#;
+
+#[derive(Default)]
+struct EventFilter {
+    project: Option<String>,
+    service: Option<String>,
+    level: Option<String>,
+    terminal: bool,
+}
+
+impl EventFilter {
+    fn from_query(query: &str) -> Self {
+        let mut filter = Self::default();
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = percent_decode(value);
+            if value.is_empty() {
+                continue;
+            }
+            match key {
+                "project" => filter.project = Some(value),
+                "service" => filter.service = Some(value),
+                "level" => filter.level = Some(value),
+                "terminal" => filter.terminal = value != "0" && value != "false",
+                _ => {}
+            }
+        }
+        filter
+    }
+
+    fn matches_log(&self, log: &LogEvent) -> bool {
+        self.project.as_ref().is_none_or(|p| &log.project_id == p)
+            && self
+                .service
+                .as_ref()
+                .is_none_or(|s| log.source.eq_ignore_ascii_case(s))
+            && self.level.as_ref().is_none_or(|l| &log.level == l)
+    }
+
+    fn matches_composite_id(&self, composite_id: &str) -> bool {
+        let (project_id, service_id) = composite_id.split_once(':').unwrap_or(("", composite_id));
+        self.project.as_ref().is_none_or(|p| project_id == p)
+            && self.service.as_ref().is_none_or(|s| service_id == s)
+    }
+
+    fn filter_status(&self, status: &StatusEvent) -> StatusEvent {
+        StatusEvent {
+            services: status
+                .services
+                .iter()
+                .filter(|(id, _)| self.matches_composite_id(id))
+                .map(|(id, status)| (id.clone(), status.clone()))
+                .collect::<HashMap<_, _>>(),
+            seq: status.seq,
+            time: status.time,
+        }
+    }
+
+    fn render_log(&self, log: &LogEvent) -> String {
+        let mut payload = serde_json::to_value(log).unwrap_or_default();
+        if self.terminal {
+            if let Some(payload) = payload.as_object_mut() {
+                payload.insert("text".to_string(), log.terminal_text().into());
+            }
+        }
+        payload.to_string()
+    }
+
+    /// Returns the SSE event name and JSON payload, or `None` if filtered out.
+    fn render(&self, event: &ManagerEvent) -> Option<(&'static str, String)> {
+        match event {
+            ManagerEvent::Log(log) => self.matches_log(log).then(|| ("log", self.render_log(log))),
+            ManagerEvent::Status(status) => Some((
+                "status",
+                serde_json::to_string(&self.filter_status(status)).unwrap_or_default(),
+            )),
+            ManagerEvent::ServiceUrl {
+                service_id,
+                url,
+                seq,
+                time,
+            } => self.matches_composite_id(service_id).then(|| {
+                (
+                    "service_url",
+                    serde_json::json!({
+                        "service_id": service_id,
+                        "url": url,
+                        "seq": seq,
+                        "time": time,
+                    })
+                    .to_string(),
+                )
+            }),
+        }
+    }
+}
+
+/// Serves the stream on `settings.host:settings.port` until the listener fails.
+pub async fn serve(
+    settings: LogServerConfig,
+    events: broadcast::Sender<ManagerEvent>,
+    process_manager: Arc<ProcessManager>,
+) -> std::io::Result<()> {
+    let listener = TcpListener::bind((settings.host.as_str(), settings.port)).await?;
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let events = events.subscribe();
+        let process_manager = process_manager.clone();
+        tokio::spawn(async move {
+            let _ = handle_connection(stream, events, process_manager).await;
+        });
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    events: broadcast::Receiver<ManagerEvent>,
+    process_manager: Arc<ProcessManager>,
+) -> std::io::Result<()> {
+    let Some(target) = read_request_target(&mut stream).await? else {
+        return write_response(&mut stream, "405 Method Not Allowed", "text/plain", "").await;
+    };
+    let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+
+    match path {
+        "/" => {
+            write_response(
+                &mut stream,
+                "200 OK",
+                "text/html; charset=utf-8",
+                INDEX_HTML,
+            )
+            .await
+        }
+        "/events" => {
+            let filter = EventFilter::from_query(query);
+            stream_events(stream, events, filter, process_manager).await
+        }
+        _ => write_response(&mut stream, "404 Not Found", "text/plain", "Not found").await,
+    }
+}
+
+/// Reads the request head and returns the target of a `GET` request.
+async fn read_request_target(stream: &mut TcpStream) -> std::io::Result<Option<String>> {
+    let mut head = Vec::new();
+    let mut buffer = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 || head.len() + read > MAX_REQUEST_HEAD {
+            return Ok(None);
+        }
+        head.extend_from_slice(&buffer[..read]);
+    }
+
+    let head = String::from_utf8_lossy(&head);
+    let mut request_line = head.lines().next().unwrap_or("").split_whitespace();
+    match (request_line.next(), request_line.next()) {
+        (Some("GET"), Some(target)) => Ok(Some(target.to_string())),
+        _ => Ok(None),
+    }
+}
+
+async fn write_response(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> std::io::Result<()> {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await
+}
+
+async fn stream_events(
+    mut stream: TcpStream,
+    mut events: broadcast::Receiver<ManagerEvent>,
+    filter: EventFilter,
+    process_manager: Arc<ProcessManager>,
+) -> std::io::Result<()> {
+    stream
+        .write_all(
+            b"HTTP/1.1 200 OK\r\n\
+              Content-Type: text/event-stream\r\n\
+              Cache-Control: no-cache\r\n\
+              Access-Control-Allow-Origin: *\r\n\
+              Connection: keep-alive\r\n\r\n",
+        )
+        .await?;
+
+    let status = filter.filter_status(&process_manager.status().await);
+    write_sse(
+        &mut stream,
+        None,
+        "status",
+        &serde_json::to_string(&status).unwrap_or_default(),
+    )
+    .await?;
+
+    let mut keep_alive = time::interval(KEEP_ALIVE_INTERVAL);
+    keep_alive.tick().await;
+
+    loop {
+        tokio::select! {
+            _ = keep_alive.tick() => {
+                stream.write_all(b": keep-alive\n\n").await?;
+            }
+            event = events.recv() => {
+                match event {
+                    Ok(event) => {
+                        if let Some((name, data)) = filter.render(&event) {
+                            write_sse(&mut stream, Some(event.seq()), name, &data).await?;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        write_sse(&mut stream, None, "lagged", &skipped.to_string()).await?;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// Writes one SSE message; `id` is the event's `seq`, so a reconnecting
+/// client reports the last one it saw in `Last-Event-ID`.
+async fn write_sse(
+    stream: &mut TcpStream,
+    id: Option<u64>,
+    event: &str,
+    data: &str,
+) -> std::io::Result<()> {
+    let id = id.map(|id| format!("id: {}\n", id)).unwrap_or_default();
+    stream
+        .write_all(format!("{}event: {}\ndata: {}\n\n", id, event, data).as_bytes())
+        .await
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                    Some(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    None => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
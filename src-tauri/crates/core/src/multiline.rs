@@ -0,0 +1,138 @@
+//! Grouping of multiline entries (stack traces) into one log event.
+//!
+//! A line matching one of the continuation patterns joins the entry above it;
+//! the entry is emitted when a non-continuation line arrives, when the output
+//! stays quiet for `flush_ms`, or when the stream ends.
+
+use crate::error::AppError;
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Entries are cut at this many lines so a runaway trace still shows up.
+const MAX_LINES: usize = 1000;
+
+/// Per-service multiline settings; `"multiline": {}` enables the defaults.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultilineConfig {
+    /// Regexes marking a line as the continuation of the previous one.
+    /// Replaces the defaults when set.
+    #[serde(default = "default_continuation")]
+    pub continuation: Vec<String>,
+    /// How long to wait for more lines before emitting a pending entry.
+    #[serde(default = "default_flush_ms")]
+    pub flush_ms: u64,
+}
+
+impl Default for MultilineConfig {
+    fn default() -> Self {
+        Self {
+            continuation: default_continuation(),
+            flush_ms: default_flush_ms(),
+        }
+    }
+}
+
+/// Indented lines (Java/Node `at ...`, Python `File ...`), plus the headers
+/// that start a nested trace.
+fn default_continuation() -> Vec<String> {
+    [r"^\s+\S", r"^\s*at\s", r"^Traceback \(", r"^Caused by:"]
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .collect()
+}
+
+fn default_flush_ms() -> u64 {
+    100
+}
+
+/// Output lines emitted as one log event.
+#[derive(Debug, Clone)]
+pub(crate) struct Entry {
+    pub(crate) lines: Vec<String>,
+    /// When the first line was read.
+    pub(crate) captured_at: DateTime<Utc>,
+}
+
+impl Entry {
+    pub(crate) fn new(line: String, captured_at: DateTime<Utc>) -> Self {
+        Self {
+            lines: vec![line],
+            captured_at,
+        }
+    }
+}
+
+/// Accumulates the lines of one stream into entries.
+#[derive(Debug, Clone)]
+pub(crate) struct Grouper {
+    continuation: Vec<Regex>,
+    pub(crate) flush_after: Duration,
+    pending: Option<Entry>,
+    in_traceback: bool,
+}
+
+impl Grouper {
+    pub(crate) fn new(config: &MultilineConfig) -> Result<Self, AppError> {
+        let continuation = config
+            .continuation
+            .iter()
+            .map(|pattern| {
+                Regex::new(pattern).map_err(|e| AppError::InvalidMultilinePattern {
+                    pattern: pattern.clone(),
+                    message: e.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            continuation,
+            flush_after: Duration::from_millis(config.flush_ms),
+            pending: None,
+            in_traceback: false,
+        })
+    }
+
+    pub(crate) fn has_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Adds `line`, read at `captured_at`, returning the entries it completes.
+    pub(crate) fn push(&mut self, line: String, captured_at: DateTime<Utc>) -> Vec<Entry> {
+        let mut done = Vec::new();
+
+        let continues = self.is_continuation(&line);
+        if let Some(pending) = self
+            .pending
+            .as_mut()
+            .filter(|pending| pending.lines.len() < MAX_LINES)
+        {
+            if continues {
+                self.in_traceback |= line.starts_with("Traceback ");
+                pending.lines.push(line);
+                return done;
+            }
+            // Python ends a traceback with an unindented `ValueError: ...`.
+            if self.in_traceback && !line.trim().is_empty() {
+                pending.lines.push(line);
+                done.extend(self.flush());
+                return done;
+            }
+        }
+
+        done.extend(self.flush());
+        self.in_traceback = line.starts_with("Traceback ");
+        self.pending = Some(Entry::new(line, captured_at));
+        done
+    }
+
+    /// Takes the pending entry, if any.
+    pub(crate) fn flush(&mut self) -> Option<Entry> {
+        self.in_traceback = false;
+        self.pending.take()
+    }
+
+    fn is_continuation(&self, line: &str) -> bool {
+        self.continuation.iter().any(|regex| regex.is_match(line))
+    }
+}
@@ -0,0 +1,556 @@
+//! Starting, stopping and observing services.
+
+use crate::ansi;
+use crate::config::{Project, Service};
+use crate::error::AppError;
+use crate::events::{LogEvent, ManagerEvent, ServiceStatus, StatusEvent, Stream};
+use crate::log_format::{parse_line, LogFormat};
+use crate::log_level::{LevelClassifier, LevelRule};
+use crate::multiline::{Entry, Grouper, MultilineConfig};
+use crate::process::{create_process_group_command, kill_process_group};
+use crate::redact::{env_file_values, Redactor};
+use crate::sink::{EventSink, StampingSink};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::process::Child;
+use tokio::sync::Mutex;
+
+#[derive(Default)]
+pub(crate) struct ProcessState {
+    pub child: Option<Child>,
+    pub running: bool,
+}
+
+/// Supervises services, each in its own process group, and reports their
+/// output and status to an [`EventSink`].
+pub struct ProcessManager {
+    processes: Arc<Mutex<HashMap<String, ProcessState>>>,
+    detected_urls: Arc<Mutex<HashMap<String, String>>>,
+    sink: Arc<StampingSink>,
+    vite_url_regex: Regex,
+}
+
+/// Everything needed to start one service.
+pub struct ServiceSpec {
+    pub project_id: String,
+    pub service_id: String,
+    pub name: String,
+    pub path: String,
+    pub command: String,
+    pub detect_url: bool,
+    pub log_format: LogFormat,
+    pub level_rules: Vec<LevelRule>,
+    pub multiline: Option<MultilineConfig>,
+    /// Custom secret regexes, from the project.
+    pub redact_patterns: Vec<String>,
+    /// Env files masked besides `.env` and `.env.local`.
+    pub env_files: Vec<String>,
+}
+
+impl ServiceSpec {
+    /// Builds the spec for `service` of `project` from its config.
+    pub fn new(project: &Project, service: &Service) -> Self {
+        Self {
+            project_id: project.id.clone(),
+            service_id: service.id.clone(),
+            name: service.name.clone(),
+            path: service.path.clone(),
+            command: service.command.clone(),
+            detect_url: service.detect_url,
+            log_format: service.log_format,
+            level_rules: service.level_rules.clone(),
+            multiline: service.multiline.clone(),
+            redact_patterns: project.redact_patterns.clone(),
+            env_files: service.env_files.clone(),
+        }
+    }
+}
+
+impl ProcessManager {
+    /// Creates a manager reporting to `sink`.
+    pub fn new(sink: impl EventSink) -> Self {
+        let vite_url_regex = Regex::new(r"(?:Local|local):\s+(https?://[^\s]+)")
+            .unwrap_or_else(|_| Regex::new("$^").expect("fallback regex should be valid"));
+        Self {
+            processes: Arc::new(Mutex::new(HashMap::new())),
+            detected_urls: Arc::new(Mutex::new(HashMap::new())),
+            sink: Arc::new(StampingSink::new(sink)),
+            vite_url_regex,
+        }
+    }
+
+    /// Sets the chrono `strftime` format of `LogEvent::timestamp` for events
+    /// emitted from now on.
+    pub fn set_timestamp_format(&self, format: &str) -> Result<(), AppError> {
+        if StrftimeItems::new(format).any(|item| item == Item::Error) {
+            return Err(AppError::InvalidTimestampFormat {
+                format: format.to_string(),
+            });
+        }
+        self.sink.set_timestamp_format(format.to_string());
+        Ok(())
+    }
+
+    /// Spawns the service and starts capturing its output.
+    ///
+    /// Fails if it is already running or cannot be spawned.
+    pub async fn start_service(&self, spec: ServiceSpec) -> Result<(), AppError> {
+        let ServiceSpec {
+            project_id,
+            service_id,
+            name,
+            path,
+            command,
+            detect_url,
+            log_format,
+            level_rules,
+            multiline,
+            redact_patterns,
+            env_files,
+        } = spec;
+        let composite_id = format!("{}:{}", project_id, service_id);
+
+        {
+            let processes = self.processes.lock().await;
+            if let Some(process) = processes.get(&composite_id) {
+                if process.running {
+                    return Err(AppError::ServiceAlreadyRunning { service_id });
+                }
+            }
+        }
+        let levels = LevelClassifier::new(&level_rules)?;
+        let multiline = multiline.as_ref().map(Grouper::new).transpose()?;
+        let redactor = Redactor::new(
+            &redact_patterns,
+            &env_file_values(Path::new(&path), &env_files),
+        )?;
+
+        let service_name = name;
+        let service_path = path;
+        let service_command = command;
+
+        self.emit_log(system_log(
+            &project_id,
+            false,
+            format!("Starting {}...", service_name),
+        ));
+
+        let parts: Vec<&str> = service_command.split_whitespace().collect();
+        if parts.is_empty() {
+            return Err(AppError::EmptyCommand);
+        }
+        let program = parts[0];
+        let args: Vec<&str> = parts[1..].to_vec();
+
+        let mut cmd = create_process_group_command(program, &args, &service_path);
+        let service_name_for_error = service_name.clone();
+        let mut child = cmd.spawn().map_err(|e| {
+            self.emit_log(system_log(
+                &project_id,
+                true,
+                format!("Failed to start {}: {}", service_name_for_error, e),
+            ));
+            AppError::ProcessStartFailed {
+                service_name: service_name_for_error,
+                message: e.to_string(),
+            }
+        })?;
+
+        let child_id = child.id();
+        let composite_id_clone = composite_id.clone();
+        let project_id_clone = project_id.clone();
+        let service_name_clone = service_name.clone();
+        let reader = OutputReader {
+            sink: self.sink.clone(),
+            detected_urls: self.detected_urls.clone(),
+            vite_url_regex: self.vite_url_regex.clone(),
+            composite_id: composite_id.clone(),
+            project_id: project_id.clone(),
+            service_id: service_id.clone(),
+            service_name: service_name.clone(),
+            detect_url,
+            log_format,
+            levels,
+            multiline,
+            redactor,
+        };
+        if let Some(stdout) = child.stdout.take() {
+            tokio::spawn(reader.clone().run(stdout, Stream::Stdout));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            tokio::spawn(reader.run(stderr, Stream::Stderr));
+        }
+
+        {
+            let mut processes = self.processes.lock().await;
+            processes.insert(
+                composite_id.clone(),
+                ProcessState {
+                    child: Some(child),
+                    running: true,
+                },
+            );
+        }
+
+        self.emit_status().await;
+
+        let processes_state = self.processes.clone();
+        let detected_urls = self.detected_urls.clone();
+        let sink = self.sink.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+                let mut processes = processes_state.lock().await;
+                if let Some(process) = processes.get_mut(&composite_id_clone) {
+                    if let Some(child) = &mut process.child {
+                        match child.try_wait() {
+                            Ok(Some(status)) => {
+                                sink.try_send(ManagerEvent::Log(system_log(
+                                    &project_id_clone,
+                                    false,
+                                    format!(
+                                        "{} stopped (PID: {:?}, {})",
+                                        service_name_clone, child_id, status
+                                    ),
+                                )));
+                                process.child = None;
+                                process.running = false;
+
+                                let mut urls = detected_urls.lock().await;
+                                urls.remove(&composite_id_clone);
+                                let status = build_status(&processes, &urls);
+                                drop(urls);
+                                drop(processes);
+                                sink.send(ManagerEvent::Status(status)).await;
+                                break;
+                            }
+                            Ok(None) => {}
+                            Err(e) => {
+                                sink.try_send(ManagerEvent::Log(system_log(
+                                    &project_id_clone,
+                                    true,
+                                    format!("Error checking {} status: {}", service_name_clone, e),
+                                )));
+                                process.child = None;
+                                process.running = false;
+                                break;
+                            }
+                        }
+                    } else {
+                        break;
+                    }
+                } else {
+                    break;
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Terminates the service's process group (`SIGTERM`, then `SIGKILL`).
+    pub async fn stop_service(
+        &self,
+        project_id: String,
+        service_id: String,
+        service_name: String,
+    ) -> Result<(), AppError> {
+        let composite_id = format!("{}:{}", project_id, service_id);
+
+        let child = {
+            let mut processes = self.processes.lock().await;
+            processes.get_mut(&composite_id).and_then(|process| {
+                process.running = false;
+                process.child.take()
+            })
+        };
+
+        if let Some(mut child) = child {
+            self.emit_log(system_log(
+                &project_id,
+                false,
+                format!("Stopping {}...", service_name),
+            ));
+
+            match kill_process_group(&mut child).await {
+                Ok(_) => match child.wait().await {
+                    Ok(status) => {
+                        self.emit_log(system_log(
+                            &project_id,
+                            false,
+                            format!("{} killed successfully ({})", service_name, status),
+                        ));
+                    }
+                    Err(e) => {
+                        self.emit_log(system_log(
+                            &project_id,
+                            true,
+                            format!("Error waiting for {}: {}", service_name, e),
+                        ));
+                    }
+                },
+                Err(e) => {
+                    self.emit_log(system_log(
+                        &project_id,
+                        true,
+                        format!("Failed to kill {}: {}", service_name, e),
+                    ));
+                }
+            }
+
+            let mut urls = self.detected_urls.lock().await;
+            urls.remove(&composite_id);
+        }
+
+        self.emit_status().await;
+        Ok(())
+    }
+
+    /// Current status of every service this manager has started.
+    pub async fn status(&self) -> StatusEvent {
+        let processes = self.processes.lock().await;
+        let urls = self.detected_urls.lock().await;
+        build_status(&processes, &urls)
+    }
+
+    /// Best-effort synchronous kill of every process group, for use from
+    /// non-async shutdown paths.
+    pub fn cleanup_processes_sync(&self) {
+        use std::process::Command;
+
+        if let Ok(processes) = self.processes.try_lock() {
+            for process in processes.values() {
+                if let Some(child) = process.child.as_ref() {
+                    if let Some(pid) = child.id() {
+                        #[cfg(unix)]
+                        {
+                            let _ = Command::new("kill")
+                                .args(["-TERM", &format!("-{}", pid)])
+                                .output();
+                            std::thread::sleep(std::time::Duration::from_millis(100));
+                            let _ = Command::new("kill")
+                                .args(["-KILL", &format!("-{}", pid)])
+                                .output();
+                        }
+
+                        #[cfg(windows)]
+                        {
+                            let _ = Command::new("taskkill")
+                                .args(["/F", "/T", "/PID", &pid.to_string()])
+                                .output();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn emit_status(&self) {
+        let status = self.status().await;
+        self.sink.send(ManagerEvent::Status(status)).await;
+    }
+
+    fn emit_log(&self, log: LogEvent) {
+        self.sink.try_send(ManagerEvent::Log(log));
+    }
+}
+
+/// Everything a stdout/stderr reader task needs to turn lines into events.
+#[derive(Clone)]
+struct OutputReader {
+    sink: Arc<dyn EventSink>,
+    detected_urls: Arc<Mutex<HashMap<String, String>>>,
+    vite_url_regex: Regex,
+    composite_id: String,
+    project_id: String,
+    service_id: String,
+    service_name: String,
+    detect_url: bool,
+    log_format: LogFormat,
+    levels: LevelClassifier,
+    multiline: Option<Grouper>,
+    redactor: Redactor,
+}
+
+impl OutputReader {
+    async fn run(mut self, output: impl AsyncRead + Unpin, stream: Stream) {
+        let mut lines = BufReader::new(output).lines();
+
+        loop {
+            let next = match &mut self.multiline {
+                Some(grouper) if grouper.has_pending() => {
+                    match tokio::time::timeout(grouper.flush_after, lines.next_line()).await {
+                        Ok(next) => next,
+                        Err(_) => {
+                            if let Some(entry) = grouper.flush() {
+                                self.emit_entry(entry, stream);
+                            }
+                            continue;
+                        }
+                    }
+                }
+                _ => lines.next_line().await,
+            };
+            let Ok(Some(line)) = next else {
+                break;
+            };
+            let captured_at = Utc::now();
+
+            if self.detect_url {
+                if let Some(url) = extract_vite_url(&self.vite_url_regex, &line) {
+                    self.record_url(url).await;
+                }
+            }
+
+            let entries = match &mut self.multiline {
+                Some(grouper) => grouper.push(line, captured_at),
+                None => vec![Entry::new(line, captured_at)],
+            };
+            for entry in entries {
+                self.emit_entry(entry, stream);
+            }
+        }
+
+        if let Some(entry) = self.multiline.as_mut().and_then(Grouper::flush) {
+            self.emit_entry(entry, stream);
+        }
+    }
+
+    fn emit_entry(&self, entry: Entry, stream: Stream) {
+        self.sink
+            .try_send(ManagerEvent::Log(self.output_log(entry, stream)));
+    }
+
+    async fn record_url(&self, url: String) {
+        {
+            let mut urls = self.detected_urls.lock().await;
+            urls.insert(self.composite_id.clone(), url.clone());
+        }
+        self.sink
+            .send(ManagerEvent::ServiceUrl {
+                service_id: self.composite_id.clone(),
+                url: url.clone(),
+                seq: 0,
+                time: DateTime::default(),
+            })
+            .await;
+        self.sink
+            .send(ManagerEvent::Log(system_log(
+                &self.project_id,
+                false,
+                format!("{} URL detected: {}", self.service_name, url),
+            )))
+            .await;
+    }
+
+    /// Builds the event for one entry: a single line, or a grouped trace
+    /// whose first line decides the level. Secrets are masked in every field
+    /// that carries output.
+    fn output_log(&self, entry: Entry, stream: Stream) -> LogEvent {
+        let Entry { lines, captured_at } = entry;
+        let line = &lines[0];
+        let parsed = parse_line(self.log_format, line);
+        // The line's own level wins over the stream: `info` on stderr is not
+        // an error, and neither is webpack's progress.
+        let severity = parsed
+            .as_ref()
+            .and_then(|parsed| parsed.severity)
+            .or_else(|| self.levels.classify(&ansi::parse(line).0));
+        let level = match severity {
+            Some(severity) => severity.display_level(),
+            None if stream == Stream::Stderr => "error",
+            None => "normal",
+        };
+        let mut message = parsed
+            .as_ref()
+            .map(|parsed| parsed.render())
+            .unwrap_or_else(|| line.clone());
+        for continuation in &lines[1..] {
+            message.push('\n');
+            message.push_str(continuation);
+        }
+        let (message, spans) = ansi::parse(&self.redactor.redact(&message));
+        let fields = parsed.map(|parsed| {
+            let mut fields = parsed.fields;
+            self.redactor.redact_fields(&mut fields);
+            fields
+        });
+
+        LogEvent {
+            source: self.service_name.to_lowercase(),
+            service_id: Some(self.service_id.clone()),
+            level: level.to_string(),
+            stream,
+            message,
+            spans,
+            raw: self.redactor.redact(&lines.join("\n")).into_owned(),
+            line_count: lines.len(),
+            severity,
+            fields,
+            seq: 0,
+            time: captured_at,
+            captured_at,
+            timestamp: String::new(),
+            project_id: self.project_id.clone(),
+        }
+    }
+}
+
+/// A message from the manager itself, shown under the `SYSTEM` source.
+///
+/// `seq`, `time` and `timestamp` are filled in by the `StampingSink`.
+fn system_log(project_id: &str, is_error: bool, message: String) -> LogEvent {
+    let level = if is_error { "error" } else { "normal" };
+    let now = Utc::now();
+    LogEvent {
+        source: "system".to_string(),
+        service_id: None,
+        level: level.to_string(),
+        stream: Stream::System,
+        message: message.clone(),
+        spans: Vec::new(),
+        raw: message,
+        line_count: 1,
+        severity: None,
+        fields: None,
+        seq: 0,
+        time: now,
+        captured_at: now,
+        timestamp: String::new(),
+        project_id: project_id.to_string(),
+    }
+}
+
+fn extract_vite_url(regex: &Regex, line: &str) -> Option<String> {
+    regex
+        .captures(line)
+        .and_then(|cap| cap.get(1))
+        .map(|m| m.as_str().to_string())
+}
+
+fn build_status(
+    processes: &HashMap<String, ProcessState>,
+    urls: &HashMap<String, String>,
+) -> StatusEvent {
+    let mut services = HashMap::new();
+    for (service_id, process) in processes.iter() {
+        services.insert(
+            service_id.clone(),
+            ServiceStatus {
+                running: process.running,
+                url: urls.get(service_id).cloned(),
+            },
+        );
+    }
+    StatusEvent {
+        services,
+        seq: 0,
+        time: Utc::now(),
+    }
+}
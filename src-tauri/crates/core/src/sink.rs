@@ -0,0 +1,94 @@
+//! Destinations for the events produced by [`ProcessManager`](crate::process_manager::ProcessManager).
+
+use crate::events::{ManagerEvent, DEFAULT_TIMESTAMP_FORMAT};
+use chrono::Utc;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use tokio::sync::{broadcast, mpsc};
+
+/// A boxed, sendable future, as returned by [`EventSink::send`].
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Receives every [`ManagerEvent`] emitted by a `ProcessManager`.
+///
+/// Implemented for Tokio `mpsc` and `broadcast` senders; implement it
+/// directly to route events anywhere else (a UI bridge, a test recorder...).
+pub trait EventSink: Send + Sync + 'static {
+    /// Delivers an event that must not be lost, such as a status change or a
+    /// detected URL. May wait for capacity.
+    fn send(&self, event: ManagerEvent) -> BoxFuture<'_, ()>;
+
+    /// Delivers a high-volume event (log lines) without waiting. Sinks may
+    /// drop it under backpressure rather than stall the output readers.
+    fn try_send(&self, event: ManagerEvent);
+}
+
+impl EventSink for mpsc::Sender<ManagerEvent> {
+    fn send(&self, event: ManagerEvent) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            let _ = mpsc::Sender::send(self, event).await;
+        })
+    }
+
+    fn try_send(&self, event: ManagerEvent) {
+        let _ = mpsc::Sender::try_send(self, event);
+    }
+}
+
+impl EventSink for broadcast::Sender<ManagerEvent> {
+    fn send(&self, event: ManagerEvent) -> BoxFuture<'_, ()> {
+        let _ = broadcast::Sender::send(self, event);
+        Box::pin(async {})
+    }
+
+    fn try_send(&self, event: ManagerEvent) {
+        let _ = broadcast::Sender::send(self, event);
+    }
+}
+
+/// Wraps the embedder's sink to number and date every event on its way out,
+/// so one counter covers every service and stream.
+pub(crate) struct StampingSink {
+    inner: Arc<dyn EventSink>,
+    next_seq: AtomicU64,
+    timestamp_format: RwLock<String>,
+}
+
+impl StampingSink {
+    pub(crate) fn new(inner: impl EventSink) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            next_seq: AtomicU64::new(1),
+            timestamp_format: RwLock::new(DEFAULT_TIMESTAMP_FORMAT.to_string()),
+        }
+    }
+
+    pub(crate) fn set_timestamp_format(&self, format: String) {
+        *self
+            .timestamp_format
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = format;
+    }
+
+    fn stamp(&self, mut event: ManagerEvent) -> ManagerEvent {
+        let seq = self.next_seq.fetch_add(1, Ordering::Relaxed);
+        let format = self
+            .timestamp_format
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        event.stamp(seq, Utc::now(), &format);
+        event
+    }
+}
+
+impl EventSink for StampingSink {
+    fn send(&self, event: ManagerEvent) -> BoxFuture<'_, ()> {
+        self.inner.send(self.stamp(event))
+    }
+
+    fn try_send(&self, event: ManagerEvent) {
+        self.inner.try_send(self.stamp(event))
+    }
+}
@@ -0,0 +1,150 @@
+//! ANSI escapes split from captured text into style spans.
+
+#![cfg(unix)]
+
+mod common;
+
+use common::{strip_ansi, Harness};
+use dev_launcher_core::ansi::{self, Color, Style, StyleSpan};
+use dev_launcher_core::events::{LogEvent, Severity, Stream};
+use std::time::Duration;
+
+const EXIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+fn span(start: usize, end: usize, style: Style) -> StyleSpan {
+    StyleSpan { start, end, style }
+}
+
+#[test]
+fn sgr_sequences_become_spans() {
+    let (text, spans) =
+        ansi::parse("\x1b[1;38;5;82mready\x1b[22m in \x1b[38;2;255;128;0m12ms\x1b[0m!");
+
+    assert_eq!(text, "ready in 12ms!");
+    let green = Some(Color::Indexed(82));
+    assert_eq!(
+        spans,
+        vec![
+            span(
+                0,
+                5,
+                Style {
+                    fg: green,
+                    bold: true,
+                    ..Style::default()
+                }
+            ),
+            span(
+                5,
+                9,
+                Style {
+                    fg: green,
+                    ..Style::default()
+                }
+            ),
+            span(
+                9,
+                13,
+                Style {
+                    fg: Some(Color::Rgb([255, 128, 0])),
+                    ..Style::default()
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn other_escapes_are_dropped() {
+    let (text, spans) = ansi::parse("\x1b[2K\x1b]8;;https://x.dev\x1b\\link\x1b]8;;\x07 \x1b[1A€");
+
+    assert_eq!(text, "link €");
+    assert!(spans.is_empty());
+}
+
+#[test]
+fn spans_count_characters_not_bytes() {
+    let (text, spans) = ansi::parse("✓ \x1b[91mfailed\x1b[m");
+
+    assert_eq!(text, "✓ failed");
+    assert_eq!((spans[0].start, spans[0].end), (2, 8));
+    assert_eq!(spans[0].style.fg, Some(Color::Indexed(9)));
+}
+
+#[test]
+fn render_restores_the_styles() {
+    let input = "\x1b[31mERROR\x1b[0m: \x1b[4;44mdown\x1b[0m";
+    let (text, spans) = ansi::parse(input);
+    let rendered = ansi::render(&text, &spans);
+
+    assert_eq!(strip_ansi(&rendered), text);
+    assert_eq!(ansi::parse(&rendered), (text, spans));
+}
+
+#[test]
+fn spans_serialize_flat() {
+    let (_, spans) = ansi::parse("\x1b[1;31mx\x1b[38;2;1;2;3my");
+
+    assert_eq!(
+        serde_json::to_value(&spans).unwrap(),
+        serde_json::json!([
+            { "start": 0, "end": 1, "fg": 1, "bold": true },
+            { "start": 1, "end": 2, "fg": [1, 2, 3], "bold": true },
+        ])
+    );
+}
+
+#[tokio::test]
+async fn log_events_carry_plain_text_and_spans() {
+    let harness = Harness::new();
+    harness
+        .manager
+        .start_service(harness.spec("api", "ansi_colors.sh", &[]))
+        .await
+        .expect("start");
+
+    harness.wait_until_stopped("api", EXIT_TIMEOUT).await;
+    let service_logs = || -> Vec<LogEvent> {
+        harness
+            .recorder
+            .logs()
+            .into_iter()
+            .filter(|log| log.stream == Stream::Stdout)
+            .collect()
+    };
+    harness
+        .wait_for("every line", EXIT_TIMEOUT, |_| service_logs().len() == 3)
+        .await;
+    let logs = service_logs();
+
+    let messages: Vec<&str> = logs.iter().map(|log| log.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec!["ERROR: connection refused", "ready in 12ms", "plain line"]
+    );
+    assert!(logs
+        .iter()
+        .all(|log| log.service_id.as_deref() == Some("api")));
+    assert!(logs[0].raw.starts_with("\x1b[31mERROR"));
+
+    // Levels are read from the visible text.
+    assert_eq!(logs[0].severity, Some(Severity::Error));
+    assert_eq!(logs[0].spans.len(), 1);
+    assert!(logs[2].spans.is_empty());
+
+    assert_eq!(
+        strip_ansi(&logs[0].terminal_text()),
+        "[API ERROR] ERROR: connection refused"
+    );
+    let system = harness
+        .recorder
+        .logs()
+        .into_iter()
+        .find(|log| log.stream == Stream::System)
+        .unwrap();
+    assert_eq!(system.service_id, None);
+    assert_eq!(
+        strip_ansi(&system.terminal_text()),
+        "[SYSTEM] Starting api..."
+    );
+}
@@ -0,0 +1,255 @@
+//! Shared harness: drives a `ProcessManager` against the shell fixtures in
+//! `tests/fixtures` and records everything it emits.
+
+#![allow(dead_code)]
+
+use dev_launcher_core::events::{LogEvent, ManagerEvent, StatusEvent};
+use dev_launcher_core::log_format::LogFormat;
+use dev_launcher_core::sink::{BoxFuture, EventSink};
+use dev_launcher_core::{ProcessManager, ServiceSpec};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+pub const PROJECT_ID: &str = "test";
+
+/// Sink that keeps every event, including the ones real sinks may drop.
+#[derive(Clone, Default)]
+pub struct Recorder {
+    events: Arc<Mutex<Vec<ManagerEvent>>>,
+}
+
+impl EventSink for Recorder {
+    fn send(&self, event: ManagerEvent) -> BoxFuture<'_, ()> {
+        self.events.lock().unwrap().push(event);
+        Box::pin(async {})
+    }
+
+    fn try_send(&self, event: ManagerEvent) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+impl Recorder {
+    pub fn events(&self) -> Vec<ManagerEvent> {
+        self.events.lock().unwrap().clone()
+    }
+
+    pub fn logs(&self) -> Vec<LogEvent> {
+        self.events()
+            .into_iter()
+            .filter_map(|event| match event {
+                ManagerEvent::Log(log) => Some(log),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn statuses(&self) -> Vec<StatusEvent> {
+        self.events()
+            .into_iter()
+            .filter_map(|event| match event {
+                ManagerEvent::Status(status) => Some(status),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Log texts with the ANSI prefix removed, e.g. `[SYSTEM] Starting svc...`.
+    pub fn log_texts(&self) -> Vec<String> {
+        self.logs()
+            .iter()
+            .map(|log| strip_ansi(&log.terminal_text()))
+            .collect()
+    }
+}
+
+pub struct Harness {
+    pub manager: ProcessManager,
+    pub recorder: Recorder,
+    scratch: PathBuf,
+}
+
+impl Harness {
+    pub fn new() -> Self {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        let scratch = std::env::temp_dir().join(format!(
+            "dev-launcher-core-test-{}-{}",
+            std::process::id(),
+            NEXT_ID.fetch_add(1, Ordering::SeqCst)
+        ));
+        std::fs::create_dir_all(&scratch).expect("create scratch dir");
+
+        let recorder = Recorder::default();
+        Self {
+            manager: ProcessManager::new(recorder.clone()),
+            recorder,
+            scratch,
+        }
+    }
+
+    /// Spec running `sh <fixture> <args>` from the fixtures directory.
+    pub fn spec(&self, service_id: &str, fixture: &str, args: &[&str]) -> ServiceSpec {
+        let mut command = format!("sh ./{}", fixture);
+        for arg in args {
+            command.push(' ');
+            command.push_str(arg);
+        }
+        ServiceSpec {
+            project_id: PROJECT_ID.to_string(),
+            service_id: service_id.to_string(),
+            name: service_id.to_string(),
+            path: fixtures_dir().to_string_lossy().into_owned(),
+            command,
+            detect_url: false,
+            log_format: LogFormat::Plain,
+            level_rules: Vec::new(),
+            multiline: None,
+            redact_patterns: Vec::new(),
+            env_files: Vec::new(),
+        }
+    }
+
+    /// File the fixtures append their PIDs to.
+    pub fn pid_file(&self) -> PathBuf {
+        self.scratch.join("pids")
+    }
+
+    pub fn recorded_pids(&self) -> Vec<i32> {
+        std::fs::read_to_string(self.pid_file())
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| line.trim().parse().ok())
+            .collect()
+    }
+
+    pub async fn is_running(&self, service_id: &str) -> bool {
+        self.manager
+            .status()
+            .await
+            .services
+            .get(&composite_id(service_id))
+            .map(|status| status.running)
+            .unwrap_or(false)
+    }
+
+    pub async fn wait_until_stopped(&self, service_id: &str, timeout: Duration) {
+        let deadline = Instant::now() + timeout;
+        while self.is_running(service_id).await {
+            assert!(
+                Instant::now() < deadline,
+                "{} still running after {:?}",
+                service_id,
+                timeout
+            );
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    }
+
+    pub async fn wait_for<F>(&self, what: &str, timeout: Duration, mut condition: F)
+    where
+        F: FnMut(&Recorder) -> bool,
+    {
+        let deadline = Instant::now() + timeout;
+        while !condition(&self.recorder) {
+            assert!(
+                Instant::now() < deadline,
+                "timed out waiting for {}; logs so far: {:#?}",
+                what,
+                self.recorder.log_texts()
+            );
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    }
+
+    pub async fn wait_for_log(&self, needle: &str, timeout: Duration) {
+        self.wait_for(needle, timeout, |recorder| {
+            recorder
+                .log_texts()
+                .iter()
+                .any(|text| text.contains(needle))
+        })
+        .await;
+    }
+
+    /// Waits until every recorded PID is gone, then returns the survivors
+    /// (empty on success).
+    pub async fn surviving_pids(&self, timeout: Duration) -> Vec<i32> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let alive: Vec<i32> = self
+                .recorded_pids()
+                .into_iter()
+                .filter(|pid| is_alive(*pid))
+                .collect();
+            if alive.is_empty() || Instant::now() >= deadline {
+                return alive;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    }
+}
+
+impl Drop for Harness {
+    fn drop(&mut self) {
+        self.manager.cleanup_processes_sync();
+        for pid in self.recorded_pids() {
+            unsafe {
+                libc::kill(pid, libc::SIGKILL);
+            }
+        }
+        let _ = std::fs::remove_dir_all(&self.scratch);
+    }
+}
+
+pub fn composite_id(service_id: &str) -> String {
+    format!("{}:{}", PROJECT_ID, service_id)
+}
+
+pub fn fixtures_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+pub fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Whether `pid` is a live (non-zombie) process.
+pub fn is_alive(pid: i32) -> bool {
+    if let Ok(stat) = std::fs::read_to_string(format!("/proc/{}/stat", pid)) {
+        // The state follows the parenthesised command name.
+        let state = stat
+            .rsplit(')')
+            .next()
+            .and_then(|rest| rest.trim().chars().next());
+        return !matches!(state, Some('Z') | Some('X'));
+    }
+    unsafe { libc::kill(pid, 0) == 0 }
+}
+
+/// Position of the first element after `from` matching `predicate`.
+pub fn position_after<T>(
+    items: &[T],
+    from: usize,
+    predicate: impl Fn(&T) -> bool,
+) -> Option<usize> {
+    items
+        .iter()
+        .skip(from)
+        .position(predicate)
+        .map(|index| index + from)
+}
@@ -0,0 +1,131 @@
+//! Sequence numbers and timestamps stamped on every emitted event.
+
+#![cfg(unix)]
+
+mod common;
+
+use common::Harness;
+use dev_launcher_core::multiline::MultilineConfig;
+use dev_launcher_core::AppError;
+use regex::Regex;
+use std::time::Duration;
+
+const EXIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[tokio::test]
+async fn every_event_gets_the_next_sequence_number() {
+    let harness = Harness::new();
+    harness
+        .manager
+        .start_service(harness.spec("svc", "interleaved.sh", &["50"]))
+        .await
+        .expect("start");
+
+    harness.wait_until_stopped("svc", EXIT_TIMEOUT).await;
+    harness
+        .wait_for("every line", EXIT_TIMEOUT, |recorder| {
+            recorder
+                .logs()
+                .iter()
+                .filter(|log| log.source == "svc")
+                .count()
+                == 100
+        })
+        .await;
+
+    let events = harness.recorder.events();
+    let seqs: Vec<u64> = events.iter().map(|event| event.seq()).collect();
+    let expected: Vec<u64> = (1..=events.len() as u64).collect();
+    assert_eq!(seqs, expected, "numbered in emission order");
+
+    // Within one stream, order and sequence agree.
+    let stderr: Vec<String> = harness
+        .recorder
+        .logs()
+        .into_iter()
+        .filter(|log| log.message.starts_with("err "))
+        .map(|log| log.message)
+        .collect();
+    let in_order: Vec<String> = (1..=50).map(|i| format!("err {}", i)).collect();
+    assert_eq!(stderr, in_order);
+}
+
+#[tokio::test]
+async fn log_events_carry_utc_times_and_a_display_timestamp() {
+    let harness = Harness::new();
+    harness
+        .manager
+        .start_service(harness.spec("svc", "exit_normally.sh", &[]))
+        .await
+        .expect("start");
+    harness.wait_until_stopped("svc", EXIT_TIMEOUT).await;
+
+    let log = harness.recorder.logs().pop().unwrap();
+    assert!(log.captured_at <= log.time);
+    assert!(Regex::new(r"^\d{2}:\d{2}:\d{2}\.\d{3}$")
+        .unwrap()
+        .is_match(&log.timestamp));
+
+    let json = serde_json::to_value(&log).unwrap();
+    let rfc3339 = Regex::new(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?Z$").unwrap();
+    assert!(rfc3339.is_match(json["time"].as_str().unwrap()), "{}", json);
+    assert!(rfc3339.is_match(json["captured_at"].as_str().unwrap()));
+    assert_eq!(json["seq"], log.seq);
+}
+
+#[tokio::test]
+async fn timestamp_format_is_configurable() {
+    let harness = Harness::new();
+    harness
+        .manager
+        .set_timestamp_format("%Y-%m-%d %H:%M:%S")
+        .expect("valid format");
+    harness
+        .manager
+        .start_service(harness.spec("svc", "exit_normally.sh", &[]))
+        .await
+        .expect("start");
+    harness.wait_until_stopped("svc", EXIT_TIMEOUT).await;
+
+    let log = harness.recorder.logs().pop().unwrap();
+    assert!(
+        Regex::new(r"^\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}$")
+            .unwrap()
+            .is_match(&log.timestamp),
+        "{}",
+        log.timestamp
+    );
+
+    let err = harness.manager.set_timestamp_format("%Q").unwrap_err();
+    assert!(matches!(err, AppError::InvalidTimestampFormat { .. }));
+}
+
+#[tokio::test]
+async fn grouped_entries_keep_the_capture_time_of_their_first_line() {
+    let harness = Harness::new();
+    let mut spec = harness.spec("api", "slow_trace.sh", &[]);
+    spec.multiline = Some(MultilineConfig {
+        flush_ms: 300,
+        ..MultilineConfig::default()
+    });
+    harness.manager.start_service(spec).await.expect("start");
+
+    harness
+        .wait_for("flushed trace", Duration::from_secs(2), |recorder| {
+            recorder.logs().iter().any(|log| log.line_count == 2)
+        })
+        .await;
+
+    let trace = harness
+        .recorder
+        .logs()
+        .into_iter()
+        .find(|log| log.line_count == 2)
+        .unwrap();
+    assert!(
+        trace.time - trace.captured_at >= chrono::Duration::milliseconds(300),
+        "captured {} emitted {}",
+        trace.captured_at,
+        trace.time
+    );
+}
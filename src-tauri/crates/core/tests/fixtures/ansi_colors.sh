@@ -0,0 +1,5 @@
+#!/bin/sh
+# Coloured output as printed by loggers and test runners, plus a line clear.
+printf '\033[31mERROR\033[0m: connection refused\n'
+printf '\033[1;38;5;82mready\033[22m in \033[38;2;255;128;0m12ms\033[0m\n'
+printf '\033[2K\033]0;title\007plain line\n'
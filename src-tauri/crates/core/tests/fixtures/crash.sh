@@ -0,0 +1,4 @@
+#!/bin/sh
+echo "booting"
+echo "fatal: boom" >&2
+exit 3
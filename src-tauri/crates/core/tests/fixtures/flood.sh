@@ -0,0 +1,3 @@
+#!/bin/sh
+# $1: number of lines to print as fast as possible.
+seq -f 'line %.0f' 1 "$1"
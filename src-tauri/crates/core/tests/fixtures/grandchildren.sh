@@ -0,0 +1,9 @@
+#!/bin/sh
+# $1: file receiving the PID of every process this fixture spawns.
+echo $$ >> "$1"
+sleep 300 &
+echo $! >> "$1"
+sh -c 'sleep 300 & echo $! >> "$0"; wait' "$1" &
+echo $! >> "$1"
+echo "ready"
+wait
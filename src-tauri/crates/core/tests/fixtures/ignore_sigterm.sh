@@ -0,0 +1,8 @@
+#!/bin/sh
+# Ignored signals stay ignored across exec, so `sleep` ignores TERM too.
+trap '' TERM
+echo $$ >> "$1"
+echo "ready"
+while true; do
+    sleep 0.1
+done
@@ -0,0 +1,8 @@
+#!/bin/sh
+# $1: number of lines to alternate between stdout and stderr.
+i=1
+while [ "$i" -le "$1" ]; do
+  echo "out $i"
+  echo "err $i" >&2
+  i=$((i + 1))
+done
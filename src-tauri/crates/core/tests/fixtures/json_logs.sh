@@ -0,0 +1,6 @@
+#!/bin/sh
+# pino- and tracing-style JSON on stderr, around a plain-text banner.
+echo "listening"
+echo '{"level":30,"time":1700000000000,"msg":"request completed","status":200}' >&2
+echo '{"level":"warn","fields":{"message":"slow query","ms":812}}' >&2
+echo 'panicked at src/main.rs' >&2
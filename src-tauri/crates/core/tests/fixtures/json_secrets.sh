@@ -0,0 +1,3 @@
+#!/bin/sh
+# A JSON line carrying secrets in its message and nested fields.
+echo '{"level":"info","msg":"connected to postgres://app:s3cr3t-pw@db/app","auth":{"header":"Bearer abc123def456"},"attempt":1}'
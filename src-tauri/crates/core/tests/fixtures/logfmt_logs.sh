@@ -0,0 +1,4 @@
+#!/bin/sh
+# logrus-style logfmt on stderr.
+echo 'time="2024-01-01T00:00:00Z" level=info msg="server started" port=8080' >&2
+echo 'level=error msg="db down" retry=3' >&2
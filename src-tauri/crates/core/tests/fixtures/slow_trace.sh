@@ -0,0 +1,5 @@
+#!/bin/sh
+# A trace followed by a long silence, so only the flush timeout can emit it.
+echo 'Error: boom' >&2
+echo '    at main (/app/index.js:1:1)' >&2
+sleep 3
@@ -0,0 +1,18 @@
+#!/bin/sh
+# Node, Python and Java traces on stderr, separated by ordinary lines.
+echo 'server starting'
+cat >&2 <<'TRACE'
+Error: connect ECONNREFUSED 127.0.0.1:5432
+    at TCPConnectWrap.afterConnect (node:net:1555:16)
+    at Protocol.connect (/app/db.js:12:7)
+ERROR:root:request failed
+Traceback (most recent call last):
+  File "/app/main.py", line 3, in <module>
+    handle()
+ValueError: boom
+Exception in thread "main" java.lang.IllegalStateException: bad
+	at com.example.App.main(App.java:5)
+Caused by: java.io.IOException: disk
+	... 1 more
+TRACE
+echo 'server ready'
@@ -0,0 +1,10 @@
+#!/bin/sh
+# Progress, warnings and errors, all on stderr as webpack/cargo/Python do.
+echo '   Compiling api v0.1.0' >&2
+echo 'warning: unused import: `std::fs`' >&2
+echo 'error[E0308]: mismatched types' >&2
+echo 'WARNING:root:disk almost full' >&2
+echo '2024-01-01 12:00:00,123 INFO started' >&2
+echo '[ERROR] connection refused' >&2
+echo 'webpack compiled successfully' >&2
+echo 'stdout line'
@@ -0,0 +1,7 @@
+#!/bin/sh
+echo ""
+echo "  VITE v5.0.0  ready in 312 ms"
+echo ""
+echo "  ➜  Local:   http://localhost:5173/"
+echo "  ➜  Network: use --host to expose"
+sleep 300
@@ -0,0 +1,109 @@
+//! Structured (`json`, `logfmt`) output parsing, end to end.
+
+#![cfg(unix)]
+
+mod common;
+
+use common::{strip_ansi, Harness};
+use dev_launcher_core::events::{LogEvent, Severity};
+use dev_launcher_core::log_format::LogFormat;
+use serde_json::json;
+use std::time::Duration;
+
+const EXIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+async fn run(fixture: &str, format: LogFormat, lines: usize) -> Vec<LogEvent> {
+    let harness = Harness::new();
+    let mut spec = harness.spec("api", fixture, &[]);
+    spec.log_format = format;
+    harness.manager.start_service(spec).await.expect("start");
+
+    harness.wait_until_stopped("api", EXIT_TIMEOUT).await;
+    harness
+        .wait_for("service output", EXIT_TIMEOUT, |recorder| {
+            recorder
+                .logs()
+                .iter()
+                .filter(|log| log.source == "api")
+                .count()
+                == lines
+        })
+        .await;
+
+    harness
+        .recorder
+        .logs()
+        .into_iter()
+        .filter(|log| log.source == "api")
+        .collect()
+}
+
+fn by_raw<'a>(logs: &'a [LogEvent], needle: &str) -> &'a LogEvent {
+    logs.iter()
+        .find(|log| log.raw.contains(needle))
+        .unwrap_or_else(|| {
+            let raw: Vec<&str> = logs.iter().map(|log| log.raw.as_str()).collect();
+            panic!("no line containing {:?} in {:#?}", needle, raw)
+        })
+}
+
+#[tokio::test]
+async fn json_lines_take_their_level_from_the_payload() {
+    let logs = run("json_logs.sh", LogFormat::Json, 4).await;
+
+    let completed = by_raw(&logs, "request completed");
+    assert_eq!(completed.severity, Some(Severity::Info));
+    assert_eq!(completed.level, "normal", "info on stderr is not an error");
+    assert_eq!(
+        strip_ansi(&completed.terminal_text()),
+        "[API] request completed status=200"
+    );
+    let fields = completed.fields.as_ref().unwrap();
+    assert_eq!(fields["status"], json!(200));
+    assert_eq!(fields["time"], json!(1_700_000_000_000u64));
+
+    let slow = by_raw(&logs, "slow query");
+    assert_eq!(slow.severity, Some(Severity::Warn));
+    assert_eq!(slow.level, "warn");
+    assert_eq!(
+        strip_ansi(&slow.terminal_text()),
+        "[API WARN] slow query ms=812"
+    );
+
+    // Lines that are not JSON fall back to the stream.
+    let banner = by_raw(&logs, "listening");
+    assert_eq!((banner.severity, banner.fields.is_none()), (None, true));
+    assert_eq!(banner.level, "normal");
+    let panic = by_raw(&logs, "panicked");
+    assert_eq!(panic.level, "error");
+    assert_eq!(panic.raw, "panicked at src/main.rs");
+}
+
+#[tokio::test]
+async fn logfmt_lines_are_decomposed() {
+    let logs = run("logfmt_logs.sh", LogFormat::Logfmt, 2).await;
+
+    let started = by_raw(&logs, "server started");
+    assert_eq!(started.severity, Some(Severity::Info));
+    assert_eq!(started.level, "normal");
+    assert_eq!(
+        strip_ansi(&started.terminal_text()),
+        "[API] server started port=8080"
+    );
+    assert_eq!(started.fields.as_ref().unwrap()["port"], json!("8080"));
+
+    let down = by_raw(&logs, "db down");
+    assert_eq!(down.severity, Some(Severity::Error));
+    assert_eq!(down.level, "error");
+    assert!(strip_ansi(&down.terminal_text()).starts_with("[API ERROR] db down retry=3"));
+}
+
+#[tokio::test]
+async fn plain_services_keep_lines_verbatim() {
+    let logs = run("logfmt_logs.sh", LogFormat::Plain, 2).await;
+
+    assert!(logs.iter().all(|log| log.level == "error"
+        && log.severity.is_none()
+        && log.fields.is_none()
+        && log.message == log.raw));
+}
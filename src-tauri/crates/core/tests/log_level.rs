@@ -0,0 +1,114 @@
+//! Level classification of plain-text output, end to end.
+
+#![cfg(unix)]
+
+mod common;
+
+use common::{strip_ansi, Harness};
+use dev_launcher_core::events::{LogEvent, Severity, Stream};
+use dev_launcher_core::log_level::LevelRule;
+use dev_launcher_core::AppError;
+use std::time::Duration;
+
+const EXIT_TIMEOUT: Duration = Duration::from_secs(5);
+const LINES: usize = 8;
+
+async fn run(level_rules: Vec<LevelRule>) -> Vec<LogEvent> {
+    let harness = Harness::new();
+    let mut spec = harness.spec("api", "stderr_levels.sh", &[]);
+    spec.level_rules = level_rules;
+    harness.manager.start_service(spec).await.expect("start");
+
+    harness.wait_until_stopped("api", EXIT_TIMEOUT).await;
+    harness
+        .wait_for("service output", EXIT_TIMEOUT, |recorder| {
+            recorder
+                .logs()
+                .iter()
+                .filter(|log| log.source == "api")
+                .count()
+                == LINES
+        })
+        .await;
+
+    harness
+        .recorder
+        .logs()
+        .into_iter()
+        .filter(|log| log.source == "api")
+        .collect()
+}
+
+fn level_of<'a>(logs: &'a [LogEvent], raw: &str) -> &'a str {
+    &logs
+        .iter()
+        .find(|log| log.raw.contains(raw))
+        .unwrap_or_else(|| panic!("no line containing {:?}", raw))
+        .level
+}
+
+#[tokio::test]
+async fn builtin_patterns_classify_stderr_lines() {
+    let logs = run(Vec::new()).await;
+
+    assert_eq!(level_of(&logs, "warning: unused import"), "warn");
+    assert_eq!(level_of(&logs, "error[E0308]"), "error");
+    assert_eq!(level_of(&logs, "WARNING:root"), "warn");
+    assert_eq!(level_of(&logs, "INFO started"), "normal");
+    assert_eq!(level_of(&logs, "[ERROR] connection refused"), "error");
+    // Unrecognised stderr lines still count as errors.
+    assert_eq!(level_of(&logs, "Compiling api"), "error");
+    assert_eq!(level_of(&logs, "stdout line"), "normal");
+
+    let warning = logs
+        .iter()
+        .find(|log| log.raw.starts_with("warning:"))
+        .unwrap();
+    assert_eq!(warning.severity, Some(Severity::Warn));
+    assert_eq!(warning.stream, Stream::Stderr);
+    assert!(strip_ansi(&warning.terminal_text()).starts_with("[API WARN] warning:"));
+
+    let stdout = logs.iter().find(|log| log.raw == "stdout line").unwrap();
+    assert_eq!((stdout.stream, stdout.severity), (Stream::Stdout, None));
+}
+
+#[tokio::test]
+async fn custom_rules_take_precedence_over_builtins() {
+    let logs = run(vec![
+        LevelRule {
+            pattern: r"^\s+Compiling ".to_string(),
+            level: Severity::Info,
+        },
+        LevelRule {
+            pattern: "compiled successfully".to_string(),
+            level: Severity::Info,
+        },
+        LevelRule {
+            pattern: "^WARNING:root".to_string(),
+            level: Severity::Error,
+        },
+    ])
+    .await;
+
+    assert_eq!(level_of(&logs, "Compiling api"), "normal");
+    assert_eq!(level_of(&logs, "compiled successfully"), "normal");
+    assert_eq!(level_of(&logs, "WARNING:root"), "error");
+    assert_eq!(level_of(&logs, "[ERROR] connection refused"), "error");
+}
+
+#[tokio::test]
+async fn invalid_rule_is_rejected_before_spawning() {
+    let harness = Harness::new();
+    let mut spec = harness.spec("api", "stderr_levels.sh", &[]);
+    spec.level_rules = vec![LevelRule {
+        pattern: "(unclosed".to_string(),
+        level: Severity::Warn,
+    }];
+
+    let err = harness.manager.start_service(spec).await.unwrap_err();
+    assert!(
+        matches!(err, AppError::InvalidLevelRule { ref pattern, .. } if pattern == "(unclosed")
+    );
+    assert!(!harness.is_running("api").await);
+    assert!(harness.recorder.logs().is_empty());
+}
@@ -0,0 +1,126 @@
+//! Multiline grouping of stack traces, end to end.
+
+#![cfg(unix)]
+
+mod common;
+
+use common::{strip_ansi, Harness};
+use dev_launcher_core::events::LogEvent;
+use dev_launcher_core::multiline::MultilineConfig;
+use dev_launcher_core::AppError;
+use std::time::Duration;
+
+const EXIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+fn service_logs(harness: &Harness) -> Vec<LogEvent> {
+    harness
+        .recorder
+        .logs()
+        .into_iter()
+        .filter(|log| log.source == "api")
+        .collect()
+}
+
+#[tokio::test]
+async fn stack_traces_are_grouped_into_one_entry() {
+    let harness = Harness::new();
+    let mut spec = harness.spec("api", "stack_traces.sh", &[]);
+    spec.multiline = Some(MultilineConfig::default());
+    harness.manager.start_service(spec).await.expect("start");
+
+    harness.wait_until_stopped("api", EXIT_TIMEOUT).await;
+    harness
+        .wait_for("every entry", EXIT_TIMEOUT, |_| {
+            service_logs(&harness).len() == 5
+        })
+        .await;
+
+    let logs = service_logs(&harness);
+    let entries: Vec<(usize, &str)> = logs
+        .iter()
+        .filter(|log| log.stream == dev_launcher_core::events::Stream::Stderr)
+        .map(|log| (log.line_count, log.raw.lines().next().unwrap()))
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            (3, "Error: connect ECONNREFUSED 127.0.0.1:5432"),
+            (5, "ERROR:root:request failed"),
+            (
+                4,
+                "Exception in thread \"main\" java.lang.IllegalStateException: bad"
+            ),
+        ]
+    );
+
+    let python = logs
+        .iter()
+        .find(|log| log.raw.starts_with("ERROR:root"))
+        .unwrap();
+    assert!(python.raw.ends_with("ValueError: boom"), "{}", python.raw);
+    assert_eq!(python.level, "error");
+    let text = strip_ansi(&python.terminal_text());
+    assert_eq!(text.matches("[API").count(), 1, "one prefix: {}", text);
+    assert!(
+        text.ends_with("\n    handle()\nValueError: boom"),
+        "{}",
+        text
+    );
+
+    let stdout: Vec<&str> = logs
+        .iter()
+        .filter(|log| log.line_count == 1)
+        .map(|log| log.raw.as_str())
+        .collect();
+    assert_eq!(stdout, vec!["server starting", "server ready"]);
+}
+
+#[tokio::test]
+async fn pending_entry_is_flushed_after_the_timeout() {
+    let harness = Harness::new();
+    let mut spec = harness.spec("api", "slow_trace.sh", &[]);
+    spec.multiline = Some(MultilineConfig {
+        flush_ms: 50,
+        ..MultilineConfig::default()
+    });
+    harness.manager.start_service(spec).await.expect("start");
+
+    harness
+        .wait_for("flushed trace", Duration::from_secs(2), |_| {
+            service_logs(&harness).iter().any(|log| log.line_count == 2)
+        })
+        .await;
+    assert!(harness.is_running("api").await, "flushed before exit");
+}
+
+#[tokio::test]
+async fn without_multiline_every_line_is_its_own_entry() {
+    let harness = Harness::new();
+    harness
+        .manager
+        .start_service(harness.spec("api", "stack_traces.sh", &[]))
+        .await
+        .expect("start");
+
+    harness.wait_until_stopped("api", EXIT_TIMEOUT).await;
+    harness
+        .wait_for("every line", EXIT_TIMEOUT, |_| {
+            service_logs(&harness).len() == 14
+        })
+        .await;
+    assert!(service_logs(&harness).iter().all(|log| log.line_count == 1));
+}
+
+#[tokio::test]
+async fn invalid_continuation_pattern_is_rejected() {
+    let harness = Harness::new();
+    let mut spec = harness.spec("api", "stack_traces.sh", &[]);
+    spec.multiline = Some(MultilineConfig {
+        continuation: vec!["[".to_string()],
+        ..MultilineConfig::default()
+    });
+
+    let err = harness.manager.start_service(spec).await.unwrap_err();
+    assert!(matches!(err, AppError::InvalidMultilinePattern { .. }));
+    assert!(!harness.is_running("api").await);
+}
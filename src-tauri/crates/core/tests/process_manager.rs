@@ -0,0 +1,292 @@
+//! End-to-end tests driving `ProcessManager` against real shell fixtures.
+
+#![cfg(unix)]
+
+mod common;
+
+use common::{composite_id, position_after, Harness};
+use dev_launcher_core::events::ManagerEvent;
+use dev_launcher_core::AppError;
+use std::time::Duration;
+
+const EXIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[tokio::test]
+async fn normal_exit_reports_output_then_stopped_status() {
+    let harness = Harness::new();
+    harness
+        .manager
+        .start_service(harness.spec("svc", "exit_normally.sh", &[]))
+        .await
+        .expect("start");
+
+    harness.wait_until_stopped("svc", EXIT_TIMEOUT).await;
+    harness.wait_for_log("[SVC] done", EXIT_TIMEOUT).await;
+
+    let texts = harness.recorder.log_texts();
+    let starting = position_after(&texts, 0, |t| t == "[SYSTEM] Starting svc...").unwrap();
+    let hello = position_after(&texts, 0, |t| t == "[SVC] hello").unwrap();
+    let done = position_after(&texts, hello, |t| t == "[SVC] done").unwrap();
+    let stopped =
+        position_after(&texts, starting, |t| t.starts_with("[SYSTEM] svc stopped")).unwrap();
+    assert!(starting < hello && hello < done, "{:#?}", texts);
+    assert!(stopped > starting, "{:#?}", texts);
+    assert!(
+        texts[stopped].contains("exit status: 0"),
+        "{}",
+        texts[stopped]
+    );
+
+    let running: Vec<bool> = harness
+        .recorder
+        .statuses()
+        .iter()
+        .map(|s| s.services[&composite_id("svc")].running)
+        .collect();
+    assert_eq!(running, vec![true, false]);
+
+    let logs = harness.recorder.logs();
+    assert!(logs
+        .iter()
+        .filter(|log| log.source == "svc")
+        .all(|log| log.level == "normal" && log.project_id == common::PROJECT_ID));
+
+    let status = harness.manager.status().await;
+    let svc = &status.services[&composite_id("svc")];
+    assert!(!svc.running);
+    assert_eq!(svc.url, None);
+}
+
+#[tokio::test]
+async fn crash_reports_stderr_as_error_and_exit_code() {
+    let harness = Harness::new();
+    harness
+        .manager
+        .start_service(harness.spec("svc", "crash.sh", &[]))
+        .await
+        .expect("start");
+
+    harness.wait_until_stopped("svc", EXIT_TIMEOUT).await;
+    harness.wait_for_log("fatal: boom", EXIT_TIMEOUT).await;
+
+    let logs = harness.recorder.logs();
+    let stderr = logs
+        .iter()
+        .find(|log| log.message.contains("fatal: boom"))
+        .unwrap();
+    assert_eq!(stderr.level, "error");
+    assert!(common::strip_ansi(&stderr.terminal_text()).starts_with("[SVC ERROR]"));
+
+    let texts = harness.recorder.log_texts();
+    let stopped = texts
+        .iter()
+        .find(|t| t.starts_with("[SYSTEM] svc stopped"))
+        .unwrap();
+    assert!(stopped.contains("exit status: 3"), "{}", stopped);
+
+    let last_status = harness.recorder.statuses().pop().unwrap();
+    assert!(!last_status.services[&composite_id("svc")].running);
+}
+
+#[tokio::test]
+async fn stop_escalates_to_sigkill_when_sigterm_is_ignored() {
+    let harness = Harness::new();
+    let pid_file = harness.pid_file();
+    harness
+        .manager
+        .start_service(harness.spec("svc", "ignore_sigterm.sh", &[pid_file.to_str().unwrap()]))
+        .await
+        .expect("start");
+    harness.wait_for_log("[SVC] ready", EXIT_TIMEOUT).await;
+
+    tokio::time::timeout(
+        EXIT_TIMEOUT,
+        harness
+            .manager
+            .stop_service(common::PROJECT_ID.into(), "svc".into(), "svc".into()),
+    )
+    .await
+    .expect("stop_service should not hang")
+    .expect("stop");
+
+    let texts = harness.recorder.log_texts();
+    let killed = texts
+        .iter()
+        .find(|t| t.starts_with("[SYSTEM] svc killed successfully"))
+        .unwrap();
+    assert!(killed.contains("signal: 9"), "{}", killed);
+
+    assert!(!harness.is_running("svc").await);
+    assert_eq!(
+        harness.surviving_pids(EXIT_TIMEOUT).await,
+        Vec::<i32>::new()
+    );
+}
+
+#[tokio::test]
+async fn stop_kills_grandchildren_in_the_process_group() {
+    let harness = Harness::new();
+    let pid_file = harness.pid_file();
+    harness
+        .manager
+        .start_service(harness.spec("svc", "grandchildren.sh", &[pid_file.to_str().unwrap()]))
+        .await
+        .expect("start");
+    harness.wait_for_log("[SVC] ready", EXIT_TIMEOUT).await;
+    harness
+        .wait_for("grandchild PIDs", EXIT_TIMEOUT, |_| {
+            harness.recorded_pids().len() == 4
+        })
+        .await;
+
+    harness
+        .manager
+        .stop_service(common::PROJECT_ID.into(), "svc".into(), "svc".into())
+        .await
+        .expect("stop");
+
+    assert_eq!(
+        harness.surviving_pids(EXIT_TIMEOUT).await,
+        Vec::<i32>::new()
+    );
+    assert!(!harness.is_running("svc").await);
+}
+
+#[tokio::test]
+async fn detects_vite_url_and_clears_it_on_stop() {
+    let harness = Harness::new();
+    let mut spec = harness.spec("web", "vite_url.sh", &[]);
+    spec.detect_url = true;
+    harness.manager.start_service(spec).await.expect("start");
+
+    harness
+        .wait_for("service URL event", EXIT_TIMEOUT, |recorder| {
+            recorder
+                .events()
+                .iter()
+                .any(|event| matches!(event, ManagerEvent::ServiceUrl { .. }))
+        })
+        .await;
+
+    let (service_id, url) = harness
+        .recorder
+        .events()
+        .into_iter()
+        .find_map(|event| match event {
+            ManagerEvent::ServiceUrl {
+                service_id, url, ..
+            } => Some((service_id, url)),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(service_id, composite_id("web"));
+    assert_eq!(url, "http://localhost:5173/");
+    harness
+        .wait_for_log(
+            "[SYSTEM] web URL detected: http://localhost:5173/",
+            EXIT_TIMEOUT,
+        )
+        .await;
+
+    let status = harness.manager.status().await;
+    assert_eq!(
+        status.services[&composite_id("web")].url.as_deref(),
+        Some("http://localhost:5173/")
+    );
+
+    harness
+        .manager
+        .stop_service(common::PROJECT_ID.into(), "web".into(), "web".into())
+        .await
+        .expect("stop");
+
+    let status = harness.recorder.statuses().pop().unwrap();
+    let web = &status.services[&composite_id("web")];
+    assert!(!web.running);
+    assert_eq!(web.url, None);
+}
+
+#[tokio::test]
+async fn flooding_stdout_delivers_every_line_in_order() {
+    const LINES: usize = 50_000;
+
+    let harness = Harness::new();
+    harness
+        .manager
+        .start_service(harness.spec("svc", "flood.sh", &[&LINES.to_string()]))
+        .await
+        .expect("start");
+
+    harness
+        .wait_until_stopped("svc", Duration::from_secs(30))
+        .await;
+    harness
+        .wait_for("every flooded line", Duration::from_secs(30), |recorder| {
+            recorder
+                .logs()
+                .iter()
+                .filter(|log| log.source == "svc")
+                .count()
+                == LINES
+        })
+        .await;
+
+    let lines: Vec<String> = harness
+        .recorder
+        .log_texts()
+        .into_iter()
+        .filter(|t| t.starts_with("[SVC] "))
+        .collect();
+    assert_eq!(lines.first().map(String::as_str), Some("[SVC] line 1"));
+    assert_eq!(lines.last().cloned(), Some(format!("[SVC] line {}", LINES)));
+    assert!(lines
+        .iter()
+        .enumerate()
+        .all(|(i, line)| *line == format!("[SVC] line {}", i + 1)));
+}
+
+#[tokio::test]
+async fn starting_a_running_service_is_rejected() {
+    let harness = Harness::new();
+    harness
+        .manager
+        .start_service(harness.spec("web", "vite_url.sh", &[]))
+        .await
+        .expect("start");
+
+    let err = harness
+        .manager
+        .start_service(harness.spec("web", "vite_url.sh", &[]))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, AppError::ServiceAlreadyRunning { ref service_id } if service_id == "web")
+    );
+
+    harness
+        .manager
+        .stop_service(common::PROJECT_ID.into(), "web".into(), "web".into())
+        .await
+        .expect("stop");
+}
+
+#[tokio::test]
+async fn stopping_an_exited_service_does_not_deadlock() {
+    let harness = Harness::new();
+    harness
+        .manager
+        .start_service(harness.spec("svc", "exit_normally.sh", &[]))
+        .await
+        .expect("start");
+    harness.wait_until_stopped("svc", EXIT_TIMEOUT).await;
+
+    tokio::time::timeout(
+        EXIT_TIMEOUT,
+        harness
+            .manager
+            .stop_service(common::PROJECT_ID.into(), "svc".into(), "svc".into()),
+    )
+    .await
+    .expect("stop_service should not hang")
+    .expect("stop");
+}
@@ -1,23 +1,18 @@
-use crate::config::{save_config, Config};
-use crate::error::AppError;
-use crate::events::StatusEvent;
-use crate::process_manager::ServiceSpec;
 use crate::state::AppState;
+use dev_launcher_core::config::Config;
+use dev_launcher_core::error::AppError;
+use dev_launcher_core::events::StatusEvent;
 use tauri::State;
 
 // Config commands
 #[tauri::command]
 pub async fn get_config(state: State<'_, AppState>) -> Result<Option<Config>, AppError> {
-    let config = state.config.lock().await;
-    Ok(config.clone())
+    Ok(state.get_config().await)
 }
 
 #[tauri::command]
 pub async fn save_app_config(config: Config, state: State<'_, AppState>) -> Result<(), AppError> {
-    save_config(&config)?;
-    let mut state_config = state.config.lock().await;
-    *state_config = Some(config);
-    Ok(())
+    state.save_config(config).await
 }
 
 #[tauri::command]
@@ -25,16 +20,7 @@ pub async fn set_active_project(
     project_id: String,
     state: State<'_, AppState>,
 ) -> Result<(), AppError> {
-    let mut config = state.config.lock().await;
-    let current = config.clone().ok_or(AppError::NoConfigLoaded)?;
-    if current.get_project(&project_id).is_none() {
-        return Err(AppError::ProjectNotFound { project_id });
-    }
-    let mut updated = current;
-    updated.active_project = Some(project_id);
-    save_config(&updated)?;
-    *config = Some(updated);
-    Ok(())
+    state.set_active_project(project_id).await
 }
 
 // Service commands
@@ -44,25 +30,7 @@ pub async fn start_service(
     service_id: String,
     state: State<'_, AppState>,
 ) -> Result<(), AppError> {
-    let spec = {
-        let config_lock = state.config.lock().await;
-        let config = config_lock.as_ref().ok_or(AppError::NoConfigLoaded)?;
-        let service = config.get_service(&project_id, &service_id).ok_or(
-            AppError::ServiceNotFound {
-                service_id: service_id.clone(),
-            },
-        )?;
-        ServiceSpec {
-            project_id,
-            service_id,
-            name: service.name.clone(),
-            path: service.path.clone(),
-            command: service.command.clone(),
-            detect_url: service.detect_url,
-        }
-    };
-
-    state.process_manager.start_service(spec).await
+    state.start_service(project_id, service_id).await
 }
 
 #[tauri::command]
@@ -71,24 +39,12 @@ pub async fn stop_service(
     service_id: String,
     state: State<'_, AppState>,
 ) -> Result<(), AppError> {
-    let service_name = {
-        let config_lock = state.config.lock().await;
-        config_lock
-            .as_ref()
-            .and_then(|config| config.get_service(&project_id, &service_id))
-            .map(|service| service.name.clone())
-            .unwrap_or_else(|| service_id.clone())
-    };
-
-    state
-        .process_manager
-        .stop_service(project_id, service_id, service_name)
-        .await
+    state.stop_service(project_id, service_id).await
 }
 
 #[tauri::command]
 pub async fn get_status(state: State<'_, AppState>) -> Result<StatusEvent, AppError> {
-    Ok(state.process_manager.status().await)
+    Ok(state.status().await)
 }
 
 #[tauri::command]
@@ -0,0 +1,321 @@
+//! Local control API for editor integrations and scripts.
+//!
+//! Listens on `control.sock` in the config directory and speaks
+//! newline-delimited JSON-RPC 2.0. Methods mirror the Tauri commands;
+//! `subscribe` turns the connection into a `ManagerEvent` stream delivered
+//! as `event` notifications, while further requests keep working.
+
+use crate::state::AppState;
+use dev_launcher_core::config::{get_config_dir, Config};
+use dev_launcher_core::error::AppError;
+use dev_launcher_core::events::ManagerEvent;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::{broadcast, mpsc};
+use tokio::task::JoinHandle;
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const APP_ERROR: i64 = -32000;
+
+#[derive(Deserialize)]
+struct Request {
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Deserialize)]
+struct ServiceParams {
+    project_id: String,
+    service_id: String,
+}
+
+#[derive(Deserialize)]
+struct ProjectParams {
+    project_id: String,
+}
+
+#[derive(Deserialize)]
+struct ConfigParams {
+    config: Config,
+}
+
+#[derive(Deserialize)]
+struct SubscribeParams {
+    project_id: Option<String>,
+}
+
+enum RpcError {
+    Protocol { code: i64, message: String },
+    App(AppError),
+}
+
+impl From<AppError> for RpcError {
+    fn from(error: AppError) -> Self {
+        RpcError::App(error)
+    }
+}
+
+pub fn socket_path() -> Option<PathBuf> {
+    get_config_dir().map(|dir| dir.join("control.sock"))
+}
+
+pub async fn serve(app: AppHandle) {
+    let Some(path) = socket_path() else {
+        return;
+    };
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+
+    if path.exists() {
+        if UnixStream::connect(&path).await.is_ok() {
+            eprintln!(
+                "Control socket {} is owned by another instance",
+                path.display()
+            );
+            return;
+        }
+        let _ = fs::remove_file(&path);
+    }
+
+    let listener = match UnixListener::bind(&path) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Failed to bind control socket {}: {}", path.display(), e);
+            return;
+        }
+    };
+    let _ = fs::set_permissions(&path, fs::Permissions::from_mode(0o600));
+
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                tokio::spawn(handle_connection(app.clone(), stream));
+            }
+            Err(e) => {
+                eprintln!("Control socket accept failed: {}", e);
+                break;
+            }
+        }
+    }
+}
+
+pub fn remove_socket() {
+    if let Some(path) = socket_path() {
+        let _ = fs::remove_file(path);
+    }
+}
+
+async fn handle_connection(app: AppHandle, stream: UnixStream) {
+    let (read_half, mut write_half) = stream.into_split();
+    let (out_tx, mut out_rx) = mpsc::channel::<String>(256);
+
+    let writer = tokio::spawn(async move {
+        while let Some(mut line) = out_rx.recv().await {
+            line.push('\n');
+            if write_half.write_all(line.as_bytes()).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut subscription: Option<JoinHandle<()>> = None;
+    let mut lines = BufReader::new(read_half).lines();
+
+    while let Ok(Some(line)) = lines.next_line().await {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let request = match serde_json::from_str::<Value>(&line) {
+            Ok(value) => match serde_json::from_value::<Request>(value) {
+                Ok(request) => request,
+                Err(e) => {
+                    let _ = out_tx
+                        .send(error_response(Value::Null, INVALID_REQUEST, e.to_string()))
+                        .await;
+                    continue;
+                }
+            },
+            Err(e) => {
+                let _ = out_tx
+                    .send(error_response(Value::Null, PARSE_ERROR, e.to_string()))
+                    .await;
+                continue;
+            }
+        };
+
+        let result = match request.method.as_str() {
+            "subscribe" => parse_params::<SubscribeParams>(request.params).map(|params| {
+                if let Some(previous) = subscription.take() {
+                    previous.abort();
+                }
+                let events = app.state::<AppState>().events.subscribe();
+                subscription = Some(tokio::spawn(forward_events(
+                    events,
+                    params.project_id,
+                    out_tx.clone(),
+                )));
+                Value::Bool(true)
+            }),
+            "unsubscribe" => {
+                if let Some(previous) = subscription.take() {
+                    previous.abort();
+                }
+                Ok(Value::Bool(true))
+            }
+            _ => dispatch(&app, &request.method, request.params).await,
+        };
+
+        let response = match result {
+            Ok(result) => {
+                json!({ "jsonrpc": "2.0", "id": request.id, "result": result }).to_string()
+            }
+            Err(RpcError::Protocol { code, message }) => error_response(request.id, code, message),
+            Err(RpcError::App(error)) => json!({
+                "jsonrpc": "2.0",
+                "id": request.id,
+                "error": {
+                    "code": APP_ERROR,
+                    "message": error.to_string(),
+                    "data": error,
+                },
+            })
+            .to_string(),
+        };
+
+        if out_tx.send(response).await.is_err() {
+            break;
+        }
+    }
+
+    if let Some(subscription) = subscription {
+        subscription.abort();
+    }
+    drop(out_tx);
+    let _ = writer.await;
+}
+
+async fn dispatch(app: &AppHandle, method: &str, params: Value) -> Result<Value, RpcError> {
+    let state = app.state::<AppState>();
+    match method {
+        "get_config" => Ok(json!(state.get_config().await)),
+        "save_config" => {
+            let ConfigParams { config } = parse_params(params)?;
+            state.save_config(config).await?;
+            notify_config_changed(app, &state).await;
+            Ok(Value::Null)
+        }
+        "set_active_project" => {
+            let ProjectParams { project_id } = parse_params(params)?;
+            state.set_active_project(project_id).await?;
+            notify_config_changed(app, &state).await;
+            Ok(Value::Null)
+        }
+        "start_service" => {
+            let ServiceParams {
+                project_id,
+                service_id,
+            } = parse_params(params)?;
+            state.start_service(project_id, service_id).await?;
+            Ok(Value::Null)
+        }
+        "stop_service" => {
+            let ServiceParams {
+                project_id,
+                service_id,
+            } = parse_params(params)?;
+            state.stop_service(project_id, service_id).await?;
+            Ok(Value::Null)
+        }
+        "get_status" => Ok(json!(state.status().await)),
+        _ => Err(RpcError::Protocol {
+            code: METHOD_NOT_FOUND,
+            message: format!("Unknown method: {}", method),
+        }),
+    }
+}
+
+/// Keeps the webview in sync when the config is changed from outside it.
+async fn notify_config_changed(app: &AppHandle, state: &AppState) {
+    let _ = app.emit("config-changed", state.get_config().await);
+}
+
+async fn forward_events(
+    mut events: broadcast::Receiver<ManagerEvent>,
+    project_id: Option<String>,
+    out_tx: mpsc::Sender<String>,
+) {
+    loop {
+        let event = match events.recv().await {
+            Ok(event) => event,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                let notification = json!({
+                    "jsonrpc": "2.0",
+                    "method": "lagged",
+                    "params": { "skipped": skipped },
+                });
+                if out_tx.send(notification.to_string()).await.is_err() {
+                    break;
+                }
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+
+        if let Some(project_id) = project_id.as_deref() {
+            if !event_belongs_to(&event, project_id) {
+                continue;
+            }
+        }
+
+        let notification = json!({
+            "jsonrpc": "2.0",
+            "method": "event",
+            "params": event,
+        });
+        if out_tx.send(notification.to_string()).await.is_err() {
+            break;
+        }
+    }
+}
+
+fn event_belongs_to(event: &ManagerEvent, project_id: &str) -> bool {
+    let prefix = format!("{}:", project_id);
+    match event {
+        ManagerEvent::Log(log) => log.project_id == project_id,
+        // Status snapshots cover every project; clients filter the map.
+        ManagerEvent::Status(_) => true,
+        ManagerEvent::ServiceUrl { service_id, .. } => service_id.starts_with(&prefix),
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    let params = if params.is_null() { json!({}) } else { params };
+    serde_json::from_value(params).map_err(|e| RpcError::Protocol {
+        code: INVALID_PARAMS,
+        message: e.to_string(),
+    })
+}
+
+fn error_response(id: Value, code: i64, message: String) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+    .to_string()
+}
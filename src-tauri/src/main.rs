@@ -1,19 +1,17 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod commands;
-mod config;
-mod events;
-mod error;
-mod process;
-mod process_manager;
+#[cfg(unix)]
+mod control;
 mod state;
 
 use commands::*;
-use config::load_config;
-use events::{LogEvent, ManagerEvent};
+use dev_launcher_core::config::load_config;
+use dev_launcher_core::events::{LogEvent, ManagerEvent};
+use dev_launcher_core::log_server;
 use state::AppState;
 use tauri::{Emitter, Manager};
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc};
 use tokio::time::{self, Duration, MissedTickBehavior};
 
 fn main() {
@@ -25,11 +23,32 @@ fn main() {
         .plugin(tauri_plugin_dialog::init())
         .setup(move |app| {
             let app_handle = app.handle();
-            tauri::async_runtime::spawn(forward_manager_events(app_handle.clone(), event_rx));
+            let state = app.state::<AppState>();
+            tauri::async_runtime::spawn(forward_manager_events(
+                app_handle.clone(),
+                event_rx,
+                state.events.clone(),
+            ));
+            #[cfg(unix)]
+            tauri::async_runtime::spawn(control::serve(app_handle.clone()));
 
             // Load config at startup
-            let state = app.state::<AppState>();
             if let Some(config) = load_config() {
+                if let Some(format) = config.timestamp_format.as_deref() {
+                    if let Err(e) = state.process_manager.set_timestamp_format(format) {
+                        eprintln!("{}", e);
+                    }
+                }
+                if let Some(settings) = config.log_server.clone() {
+                    let events = state.events.clone();
+                    let process_manager = state.process_manager.clone();
+                    tauri::async_runtime::spawn(async move {
+                        if let Err(e) = log_server::serve(settings, events, process_manager).await
+                        {
+                            eprintln!("Log server stopped: {}", e);
+                        }
+                    });
+                }
                 let mut state_config = state.config.blocking_lock();
                 *state_config = Some(config);
             }
@@ -51,6 +70,8 @@ fn main() {
                 if let Some(state) = app.try_state::<AppState>() {
                     cleanup_processes_sync(&state);
                 }
+                #[cfg(unix)]
+                control::remove_socket();
             }
         })
         .run(tauri::generate_context!())
@@ -64,6 +85,7 @@ fn cleanup_processes_sync(state: &AppState) {
 async fn forward_manager_events(
     app: tauri::AppHandle,
     mut event_rx: mpsc::Receiver<ManagerEvent>,
+    events: broadcast::Sender<ManagerEvent>,
 ) {
     const LOG_FLUSH_INTERVAL: Duration = Duration::from_millis(100);
     const LOG_BATCH_SIZE: usize = 200;
@@ -78,6 +100,11 @@ async fn forward_manager_events(
                 flush_logs(&app, &mut pending_logs);
             }
             event = event_rx.recv() => {
+                if let Some(event) = event.as_ref() {
+                    if events.receiver_count() > 0 {
+                        let _ = events.send(event.clone());
+                    }
+                }
                 match event {
                     Some(ManagerEvent::Log(log)) => {
                         pending_logs.push(log);
@@ -88,12 +115,19 @@ async fn forward_manager_events(
                     Some(ManagerEvent::Status(status)) => {
                         let _ = app.emit("status-change", status);
                     }
-                    Some(ManagerEvent::ServiceUrl { service_id, url }) => {
+                    Some(ManagerEvent::ServiceUrl {
+                        service_id,
+                        url,
+                        seq,
+                        time,
+                    }) => {
                         let _ = app.emit(
                             "service-url",
                             serde_json::json!({
                                 "serviceId": service_id,
                                 "url": url,
+                                "seq": seq,
+                                "time": time,
                             }),
                         );
                     }
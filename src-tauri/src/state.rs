@@ -1,12 +1,15 @@
-use crate::config::Config;
-use crate::events::ManagerEvent;
-use crate::process_manager::ProcessManager;
+use dev_launcher_core::config::{save_config, Config};
+use dev_launcher_core::error::AppError;
+use dev_launcher_core::events::{ManagerEvent, StatusEvent, DEFAULT_TIMESTAMP_FORMAT};
+use dev_launcher_core::process_manager::{ProcessManager, ServiceSpec};
 use std::sync::Arc;
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::{broadcast, mpsc, Mutex};
 
 pub struct AppState {
     pub config: Arc<Mutex<Option<Config>>>,
     pub process_manager: Arc<ProcessManager>,
+    /// Fan-out of every `ManagerEvent` for consumers other than the webview.
+    pub events: broadcast::Sender<ManagerEvent>,
 }
 
 impl Default for AppState {
@@ -18,9 +21,93 @@ impl Default for AppState {
 
 impl AppState {
     pub fn new(event_tx: mpsc::Sender<ManagerEvent>) -> Self {
+        let (events, _) = broadcast::channel(1024);
         Self {
             config: Arc::new(Mutex::new(None)),
             process_manager: Arc::new(ProcessManager::new(event_tx)),
+            events,
         }
     }
+
+    // The methods below back both the Tauri commands and the control socket.
+
+    pub async fn get_config(&self) -> Option<Config> {
+        self.config.lock().await.clone()
+    }
+
+    pub async fn save_config(&self, config: Config) -> Result<(), AppError> {
+        self.process_manager.set_timestamp_format(
+            config
+                .timestamp_format
+                .as_deref()
+                .unwrap_or(DEFAULT_TIMESTAMP_FORMAT),
+        )?;
+        save_config(&config)?;
+        let mut state_config = self.config.lock().await;
+        *state_config = Some(config);
+        Ok(())
+    }
+
+    pub async fn set_active_project(&self, project_id: String) -> Result<(), AppError> {
+        let mut config = self.config.lock().await;
+        let current = config.clone().ok_or(AppError::NoConfigLoaded)?;
+        if current.get_project(&project_id).is_none() {
+            return Err(AppError::ProjectNotFound { project_id });
+        }
+        let mut updated = current;
+        updated.active_project = Some(project_id);
+        save_config(&updated)?;
+        *config = Some(updated);
+        Ok(())
+    }
+
+    pub async fn start_service(
+        &self,
+        project_id: String,
+        service_id: String,
+    ) -> Result<(), AppError> {
+        let spec = {
+            let config_lock = self.config.lock().await;
+            let config = config_lock.as_ref().ok_or(AppError::NoConfigLoaded)?;
+            let project =
+                config
+                    .get_project(&project_id)
+                    .ok_or_else(|| AppError::ProjectNotFound {
+                        project_id: project_id.clone(),
+                    })?;
+            let service = project
+                .services
+                .iter()
+                .find(|service| service.id == service_id)
+                .ok_or(AppError::ServiceNotFound {
+                    service_id: service_id.clone(),
+                })?;
+            ServiceSpec::new(project, service)
+        };
+
+        self.process_manager.start_service(spec).await
+    }
+
+    pub async fn stop_service(
+        &self,
+        project_id: String,
+        service_id: String,
+    ) -> Result<(), AppError> {
+        let service_name = {
+            let config_lock = self.config.lock().await;
+            config_lock
+                .as_ref()
+                .and_then(|config| config.get_service(&project_id, &service_id))
+                .map(|service| service.name.clone())
+                .unwrap_or_else(|| service_id.clone())
+        };
+
+        self.process_manager
+            .stop_service(project_id, service_id, service_name)
+            .await
+    }
+
+    pub async fn status(&self) -> StatusEvent {
+        self.process_manager.status().await
+    }
 }
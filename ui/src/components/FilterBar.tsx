@@ -10,6 +10,7 @@ export function FilterBar({ filters, onFiltersChange, sourceOptions }: FilterBar
   const levels: { value: LogLevel; label: string }[] = [
     { value: 'all', label: 'All' },
     { value: 'normal', label: 'Normal' },
+    { value: 'warn', label: 'Warn' },
     { value: 'error', label: 'Error' },
   ];
 
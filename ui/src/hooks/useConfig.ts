@@ -1,4 +1,5 @@
 import { invoke } from '@tauri-apps/api/core';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
 import { useCallback, useEffect, useState } from 'react';
 import type { Config, Project, Service } from '../types/events';
 
@@ -18,6 +19,21 @@ export function useConfig() {
       });
   }, []);
 
+  // The config can also be changed through the control socket.
+  useEffect(() => {
+    let unlisten: UnlistenFn | null = null;
+
+    listen<Config | null>('config-changed', (event) => {
+      setConfig(event.payload);
+    }).then((fn) => {
+      unlisten = fn;
+    });
+
+    return () => {
+      unlisten?.();
+    };
+  }, []);
+
   const saveConfig = useCallback(async (newConfig: Config) => {
     try {
       await invoke('save_app_config', { config: newConfig });
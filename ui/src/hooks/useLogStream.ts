@@ -1,6 +1,6 @@
 import { listen, UnlistenFn } from '@tauri-apps/api/event';
 import { useEffect, useRef, useCallback } from 'react';
-import type { LogEvent, Filters } from '../types/events';
+import type { LogEvent, Filters, StyleSpan, Color } from '../types/events';
 import type { TerminalHandle } from '../components/Terminal';
 
 const MAX_LOGS = 20000;
@@ -44,6 +44,58 @@ const getLogAt = (buffer: LogBuffer, index: number): LogEvent | undefined => {
   return buffer.items[itemIndex];
 };
 
+// Label colours as ANSI palette slots, so the terminal theme decides the shade
+const LABEL_COLORS = {
+  error: '91',
+  warn: '33',
+  system: '35',
+  service: '94',
+};
+
+const colorParams = (base: number, color: Color): string =>
+  typeof color === 'number'
+    ? `${base};5;${color}`
+    : `${base};2;${color[0]};${color[1]};${color[2]}`;
+
+const spanParams = (span: StyleSpan): string => {
+  const params: string[] = [];
+  if (span.bold) params.push('1');
+  if (span.dim) params.push('2');
+  if (span.italic) params.push('3');
+  if (span.underline) params.push('4');
+  if (span.fg !== undefined) params.push(colorParams(38, span.fg));
+  if (span.bg !== undefined) params.push(colorParams(48, span.bg));
+  return params.join(';');
+};
+
+const renderMessage = (message: string, spans: StyleSpan[] | undefined): string => {
+  if (!spans?.length) return message;
+
+  const chars = Array.from(message);
+  let out = '';
+  let index = 0;
+  for (const span of spans) {
+    out += chars.slice(index, span.start).join('');
+    out += `\x1b[${spanParams(span)}m${chars.slice(span.start, span.end).join('')}\x1b[0m`;
+    index = span.end;
+  }
+  return out + chars.slice(index).join('');
+};
+
+const formatLogLine = (log: LogEvent): string => {
+  const source = log.source.toUpperCase();
+  let label = `[${source}]`;
+  let color = log.stream === 'system' ? LABEL_COLORS.system : LABEL_COLORS.service;
+  if (log.level === 'error') {
+    label = `[${source} ERROR]`;
+    color = LABEL_COLORS.error;
+  } else if (log.level === 'warn') {
+    label = `[${source} WARN]`;
+    color = LABEL_COLORS.warn;
+  }
+  return `\x1b[${color}m${label}\x1b[0m ${renderMessage(log.message, log.spans)}`;
+};
+
 export function useLogStream(
   terminalRef: React.RefObject<TerminalHandle | null>,
   filters: Filters,
@@ -99,7 +151,7 @@ export function useLogStream(
       while (index < total && written < REWRITE_CHUNK_SIZE) {
         const log = getLogAt(buffer, index);
         if (log && shouldDisplayLog(log)) {
-          terminalRef.current.writeln(log.text);
+          terminalRef.current.writeln(formatLogLine(log));
         }
         index += 1;
         written += 1;
@@ -138,7 +190,7 @@ export function useLogStream(
 
         pushLog(logBufferRef.current, newLog);
         if (shouldDisplayLog(newLog)) {
-          batchParts.push(`${newLog.text}\r\n`);
+          batchParts.push(`${formatLogLine(newLog)}\r\n`);
         }
       }
 
@@ -5,29 +5,78 @@ export interface Service {
   path: string;
   command: string;
   detect_url: boolean;
+  log_format?: LogFormat;
+  level_rules?: LevelRule[];
+  multiline?: MultilineConfig;
 }
 
+export interface MultilineConfig {
+  continuation?: string[];
+  flush_ms?: number;
+}
+
+export interface LevelRule {
+  pattern: string;
+  level: Severity;
+}
+
+export type LogFormat = 'plain' | 'json' | 'logfmt';
+
 export interface Project {
   id: string;
   name: string;
   services: Service[];
 }
 
+export interface LogServerConfig {
+  host: string;
+  port: number;
+}
+
 export interface Config {
   version: number;
   active_project: string | null;
   projects: Project[];
+  log_server?: LogServerConfig | null;
+  timestamp_format?: string;
 }
 
 // Log types
 export interface LogEvent {
   source: string;
-  level: 'normal' | 'error';
-  text: string;
+  service_id: string | null;
+  level: 'normal' | 'warn' | 'error';
+  stream: 'stdout' | 'stderr' | 'system';
+  message: string;
+  spans?: StyleSpan[];
+  raw: string;
+  line_count: number;
+  severity: Severity | null;
+  fields: Record<string, unknown> | null;
+  seq: number;
+  time: string; // RFC 3339, UTC
+  captured_at: string; // RFC 3339, UTC
   timestamp: string;
   project_id: string;
 }
 
+// Palette index, or [r, g, b]
+export type Color = number | [number, number, number];
+
+// Styled range of `message`, in code points
+export interface StyleSpan {
+  start: number;
+  end: number;
+  fg?: Color;
+  bg?: Color;
+  bold?: boolean;
+  dim?: boolean;
+  italic?: boolean;
+  underline?: boolean;
+}
+
+export type Severity = 'trace' | 'debug' | 'info' | 'warn' | 'error' | 'fatal';
+
 // Status types
 export interface ServiceStatus {
   running: boolean;
@@ -36,10 +85,12 @@ export interface ServiceStatus {
 
 export interface StatusEvent {
   services: Record<string, ServiceStatus>;
+  seq: number;
+  time: string;
 }
 
 // Filter types
-export type LogLevel = 'normal' | 'error' | 'all';
+export type LogLevel = 'normal' | 'warn' | 'error' | 'all';
 
 export interface Filters {
   source: 'all' | 'system' | string; // string = service name